@@ -57,6 +57,14 @@ function hasSafeProtocol(raw, allowDataImage) {
   }
 }
 
+function isSameOriginUrl(raw) {
+  try {
+    return new URL(String(raw || ""), location.origin).origin === location.origin;
+  } catch (e) {
+    return false;
+  }
+}
+
 function hasSafeSrcSet(raw) {
   const parts = String(raw || "").split(",");
   for (const part of parts) {
@@ -109,6 +117,16 @@ const FORBIDDEN_HTML_TAGS = new Set([
   "style",
 ]);
 
+// Nonce for <style> blocks carried over on full-page swaps. Per-response
+// nonces in fetched markup never match the live document's policy, so
+// styles carrying the nonce the server reported for that response
+// (silcrow-nonce header) are re-stamped with the nonce this page was
+// served with.
+const CSP_NONCE =
+  document.currentScript?.nonce ||
+  document.querySelector('meta[name="csp-nonce"]')?.getAttribute("content") ||
+  "";
+
 function hardenBlankTargets(node) {
   if (node.tagName !== "A") return;
   if (String(node.getAttribute("target") || "").toLowerCase() !== "_blank") return;
@@ -179,6 +197,21 @@ function safeSetHTML(el, raw, options = {}) {
   const doc = new DOMParser().parseFromString(markup, "text/html");
   sanitizeTree(doc.body, options);
 
+  // Under a nonce policy, only styles whose nonce matches the one the
+  // server reported for this response are kept; anything else (missing,
+  // empty or guessed nonce, e.g. injected through unescaped content) is
+  // dropped rather than handed a valid nonce.
+  if (options.allowStyleTags && CSP_NONCE) {
+    const responseNonce = options.responseNonce || "";
+    for (const style of doc.body.querySelectorAll("style")) {
+      if (responseNonce && style.getAttribute("nonce") === responseNonce) {
+        style.setAttribute("nonce", CSP_NONCE);
+      } else {
+        style.remove();
+      }
+    }
+  }
+
   el.innerHTML = doc.body.innerHTML;
 }
 
@@ -1205,7 +1238,7 @@ function connectSseHub(hub) {
   });
 
   es.addEventListener("navigate", function (e) {
-    if (e.data) navigate(e.data.trim(), {trigger: "sse"});
+    if (e.data) navigateSameOrigin(e.data.trim(), {trigger: "sse"});
   });
 
   es.addEventListener("custom", function (e) {
@@ -1498,7 +1531,7 @@ function dispatchWsMessage(hub, rawData) {
     } else if (type === "navigate") {
       // Navigate runs once, not per subscriber
       if (msg.path) {
-        navigate(msg.path.trim(), {trigger: "ws"});
+        navigateSameOrigin(msg.path.trim(), {trigger: "ws"});
       }
     } else if (type === "custom") {
       // Custom event dispatched once on document
@@ -1623,6 +1656,11 @@ const abortMap = new WeakMap();
 let routeHandler = null;
 let errorHandler = null;
 const responseCache = new Map();
+// Position and arrival transition of the current history entry, used to
+// tell back from forward on popstate and to replay the departing transition.
+let historyIdx = 0;
+let historyTransition = null;
+let transitionSeq = 0;
 const preloadInflight = new Map();
 
 // ── Verb Resolution ────────────────────────────────────────
@@ -1726,19 +1764,25 @@ function hideLoading(el) {
 }
 
 // ── Cache Management ───────────────────────────────────────
-function cacheSet(url, entry) {
-  responseCache.set(url, entry);
+// The same URL can answer with HTML (s-html) or JSON depending on the
+// Accept header, so entries are keyed by representation as well.
+function cacheKey(url, wantsHTML) {
+  return (wantsHTML ? "html " : "json ") + url;
+}
+
+function cacheSet(key, entry) {
+  responseCache.set(key, entry);
   if (responseCache.size > MAX_CACHE) {
     const oldest = responseCache.keys().next().value;
     responseCache.delete(oldest);
   }
 }
 
-function cacheGet(url) {
-  const cached = responseCache.get(url);
+function cacheGet(key) {
+  const cached = responseCache.get(key);
   if (!cached) return null;
   if (Date.now() - cached.ts > CACHE_TTL) {
-    responseCache.delete(url);
+    responseCache.delete(key);
     return null;
   }
   return cached;
@@ -1779,7 +1823,7 @@ function processSideEffectHeaders(sideEffects, primaryTarget) {
   }
 
   if (sideEffects.navigate) {
-    navigate(sideEffects.navigate, {trigger: "header"});
+    navigateSameOrigin(sideEffects.navigate, {trigger: "header"});
   }
 
   if (sideEffects.sse) {
@@ -1832,6 +1876,8 @@ function processResponseHeaders(response, fullUrl) {
     finalUrl: response.url || fullUrl,
     pushUrl: null,
     retargetSelector: null,
+    transition: response.headers.get("silcrow-transition"),
+    scroll: response.headers.get("silcrow-scroll"),
     sideEffects: {
       patch: response.headers.get("silcrow-patch"),
       invalidate: response.headers.get("silcrow-invalidate"),
@@ -1859,6 +1905,10 @@ function processResponseHeaders(response, fullUrl) {
 
   // Push URL override
   result.pushUrl = response.headers.get("silcrow-push");
+  if (result.pushUrl && !isSameOriginUrl(result.pushUrl)) {
+    warn("Rejected cross-origin silcrow-push URL: " + result.pushUrl);
+    result.pushUrl = null;
+  }
   if (result.pushUrl) {
     result.finalUrl = new URL(result.pushUrl, location.origin).href;
     result.redirected = true;
@@ -1884,26 +1934,61 @@ function prepareSwapContent(text, contentType, targetSelector) {
   return {swapContent, isJSON};
 }
 
+// ── View Transitions ───────────────────────────────────────
+// Runs the swap inside document.startViewTransition when a transition
+// name is requested. The name and direction ("forward" | "back") are
+// exposed as [data-silcrow-transition] and
+// [data-silcrow-transition-direction] on <html> for the duration so CSS
+// can pick and reverse the animation.
+async function swapWithTransition(proceed, name, direction) {
+  if (!name || typeof document.startViewTransition !== "function") {
+    proceed();
+    return;
+  }
+
+  const root = document.documentElement;
+  const token = ++transitionSeq;
+  root.setAttribute("data-silcrow-transition", name);
+  root.setAttribute("data-silcrow-transition-direction", direction);
+  // Only the latest transition clears the attributes; an earlier one
+  // finishing (even with the same name) must not strip a running one.
+  const clear = () => {
+    if (transitionSeq === token) {
+      root.removeAttribute("data-silcrow-transition");
+      root.removeAttribute("data-silcrow-transition-direction");
+    }
+  };
+
+  const vt = document.startViewTransition(proceed);
+  // ready rejects when the transition is skipped (e.g. a newer one starts)
+  vt.ready.catch(() => {});
+  vt.finished.then(clear, clear);
+  await vt.updateCallbackDone;
+}
+
 // ── Post-Swap Finalization ─────────────────────────────────
 function finalizeNavigation(ctx) {
   const {pushUrl, redirected, finalUrl, fullUrl, shouldPushHistory,
-    trigger, targetSelector, targetEl, sideEffects} = ctx;
+    trigger, targetSelector, targetEl, sideEffects, transition, scroll, isJSON,
+    wantsHTML} = ctx;
 
   processSideEffectHeaders(sideEffects, targetEl);
 
   const finalHistoryUrl = pushUrl || (redirected ? finalUrl : fullUrl);
   if (shouldPushHistory && trigger !== "popstate") {
     history.pushState(
-      {silcrow: true, url: finalHistoryUrl, targetSelector},
+      {silcrow: true, url: finalHistoryUrl, targetSelector, transition, wantsHTML, idx: historyIdx + 1},
       "",
       finalHistoryUrl
     );
+    historyIdx += 1;
+    historyTransition = transition;
   }
 
   if (trigger === "popstate") {
     const saved = (history.state || {}).scrollY;
     window.scrollTo(0, saved || 0);
-  } else if (shouldPushHistory) {
+  } else if (scroll === "top" || (shouldPushHistory && scroll !== "preserve")) {
     window.scrollTo(0, 0);
   }
 
@@ -1921,6 +2006,9 @@ function finalizeNavigation(ctx) {
       const url = el.getAttribute("s-sse");
       if (url) openLive(el, url);
     });
+    // JSON patches leave existing forms in place; only restore into markup
+    // that was just swapped in.
+    if (!isJSON) initDrafts(targetEl);
   }
 }
 
@@ -1933,7 +2021,12 @@ async function navigate(url, options = {}) {
     trigger = "click",
     skipHistory = false,
     sourceEl = null,
+    direction = "forward",
+    wantsHTML: wantsHTMLOption = null,
   } = options;
+  let transition = options.transition ||
+    sourceEl?.closest?.("[s-transition]")?.getAttribute("s-transition") || null;
+  let scroll = sourceEl?.closest?.("[s-scroll]")?.getAttribute("s-scroll") || null;
 
   const fullUrl = new URL(url, location.origin).href;
   let targetEl = target || document.body;
@@ -1962,21 +2055,32 @@ async function navigate(url, options = {}) {
   showLoading(targetEl);
 
   try {
-    let cached = method === "GET" ? cacheGet(fullUrl) : null;
+    // Popstate has no source element; the entry records which
+    // representation it was navigated with.
+    const wantsHTML = wantsHTMLOption ?? !!sourceEl?.hasAttribute("s-html");
+    const cached = method === "GET" ? cacheGet(cacheKey(fullUrl, wantsHTML)) : null;
 
     let text, contentType, redirected = false, finalUrl = fullUrl, pushUrl = null;
-    let sideEffects = null;
+    let sideEffects = null, responseNonce = null;
 
-    const wantsHTML = sourceEl?.hasAttribute("s-html");
     if (cached) {
       // Side-effect headers are intentionally not cached — they are
       // one-shot triggers that should only fire on the original response.
       text = cached.text;
       contentType = cached.contentType;
+      if (trigger !== "popstate") transition = cached.transition || transition;
+      scroll = cached.scroll || scroll;
+      responseNonce = cached.nonce || null;
     } else {
       const fetchOpts = buildFetchOptions(method, body, wantsHTML, controller.signal);
       const response = await fetch(fullUrl, fetchOpts);
 
+      // fetch follows 3xx transparently; a redirect to a user-controlled
+      // ?next= must not get its markup swapped in or its URL pushed/assigned.
+      if (response.url && new URL(response.url).origin !== new URL(fullUrl).origin) {
+        throw new Error("[silcrow] Refused cross-origin redirect: " + response.url);
+      }
+
       if (!response.ok) {
         throw new Error(`HTTP ${response.status}: ${response.statusText}`);
       }
@@ -1995,6 +2099,10 @@ async function navigate(url, options = {}) {
       finalUrl = headerResult.finalUrl;
       pushUrl = headerResult.pushUrl;
       sideEffects = headerResult.sideEffects;
+      // On popstate the transition comes from history (departing entry on
+      // back), not from the entered page's own response.
+      if (trigger !== "popstate") transition = headerResult.transition || transition;
+      scroll = headerResult.scroll || scroll;
 
       // Apply retarget
       if (headerResult.retargetSelector) {
@@ -2004,10 +2112,15 @@ async function navigate(url, options = {}) {
 
       text = await response.text();
       contentType = response.headers.get("Content-Type") || "";
+      responseNonce = response.headers.get("silcrow-nonce");
 
       const cacheControl = response.headers.get("silcrow-cache");
       if (method === "GET" && !redirected && cacheControl !== "no-cache") {
-        cacheSet(fullUrl, {text, contentType, ts: Date.now()});
+        cacheSet(cacheKey(fullUrl, wantsHTML), {
+          text, contentType, ts: Date.now(),
+          transition: headerResult.transition, scroll: headerResult.scroll,
+          nonce: responseNonce,
+        });
       }
 
       if (method !== "GET") {
@@ -2015,6 +2128,11 @@ async function navigate(url, options = {}) {
       }
     }
 
+    // Cached or fresh, the submit succeeded: its draft is no longer needed.
+    if (sourceEl && sourceEl.tagName === "FORM" && sourceEl.hasAttribute("s-autosave")) {
+      clearDraft(sourceEl);
+    }
+
     // Route handler middleware
     if (routeHandler) {
       const handled = await routeHandler({
@@ -2049,6 +2167,7 @@ async function navigate(url, options = {}) {
       } else {
         safeSetHTML(targetEl, swapContent, {
           allowStyleTags: method === "GET" && !targetSelector && targetEl === document.body,
+          responseNonce,
         });
       }
     };
@@ -2060,7 +2179,7 @@ async function navigate(url, options = {}) {
     });
 
     if (!document.dispatchEvent(beforeSwap)) return;
-    if (!swapExecuted) proceed();
+    if (!swapExecuted) await swapWithTransition(proceed, transition, direction);
 
     // Mirror top-level GET JSON into the route atom for headless consumers.
     // Skip fragment swaps (s-target set), non-GET, and HTML responses.
@@ -2076,7 +2195,7 @@ async function navigate(url, options = {}) {
     finalizeNavigation({
       pushUrl, redirected, finalUrl, fullUrl,
       shouldPushHistory, trigger, targetSelector, targetEl,
-      sideEffects,
+      sideEffects, transition, scroll, isJSON, wantsHTML,
     });
 
   } catch (err) {
@@ -2118,6 +2237,29 @@ async function navigate(url, options = {}) {
   }
 }
 
+// ── Redirect Sinks ─────────────────────────────────────────
+// Server headers, live messages and Silcrow.go can carry user-influenced
+// URLs (e.g. ?next=), so they are held to the page origin. Author-written
+// verb attributes go through navigate() directly.
+function navigateSameOrigin(url, options) {
+  if (!isSameOriginUrl(url)) {
+    const err = new Error("[silcrow] Refused cross-origin navigation: " + url);
+    if (errorHandler) {
+      errorHandler(err, {url, method: options.method || "GET", trigger: options.trigger, target: null});
+    } else {
+      console.error(err);
+    }
+    document.dispatchEvent(
+      new CustomEvent("silcrow:error", {
+        bubbles: true,
+        detail: {error: err, url},
+      })
+    );
+    return Promise.resolve();
+  }
+  return navigate(url, options);
+}
+
 // ── Click Handler (opt-in: verb attributes + s-boost) ──────
 async function onClick(e) {
   if (e.metaKey || e.ctrlKey || e.shiftKey || e.altKey) return;
@@ -2130,7 +2272,7 @@ async function onClick(e) {
     e.preventDefault();
     const verb = resolveVerb(el);
     if (!verb) return;
-    const inflight = preloadInflight.get(verb.url);
+    const inflight = preloadInflight.get(cacheKey(verb.url, el.hasAttribute("s-html")));
     if (inflight) await inflight;
     navigate(verb.url, {
       method: verb.method,
@@ -2150,7 +2292,7 @@ async function onClick(e) {
 
   e.preventDefault();
   const boostedUrl = new URL(anchor.getAttribute("href"), location.origin).href;
-  const inflight = preloadInflight.get(boostedUrl);
+  const inflight = preloadInflight.get(cacheKey(boostedUrl, anchor.hasAttribute("s-html")));
   if (inflight) await inflight;
 
   navigate(boostedUrl, {
@@ -2211,17 +2353,29 @@ function onPopState(e) {
     ? document.querySelector(targetSelector)
     : document.body;
 
+  // Going back replays the transition of the entry being left (the one
+  // used to arrive there); going forward replays the entered entry's own.
+  const idx = typeof state.idx === "number" ? state.idx : 0;
+  const direction = idx < historyIdx ? "back" : "forward";
+  const transition = direction === "back" ? historyTransition : (state.transition || null);
+  historyIdx = idx;
+  historyTransition = state.transition || null;
+
   navigate(url, {
     method: "GET",
     target: target || document.body,
     trigger: "popstate",
     skipHistory: true,
+    transition,
+    direction,
+    wantsHTML: !!state.wantsHTML,
   });
 }
 
 // ── Preload Handler ────────────────────────────────────────
 function startPreload(url, wantsHTML) {
-  if (responseCache.has(url) || preloadInflight.has(url)) return;
+  const key = cacheKey(url, wantsHTML);
+  if (responseCache.has(key) || preloadInflight.has(key)) return;
   const controller = new AbortController();
   const promise = fetch(url, {
     headers: {"silcrow-target": "true", "Accept": wantsHTML ? "text/html" : "application/json"},
@@ -2229,21 +2383,25 @@ function startPreload(url, wantsHTML) {
   })
     .then((r) => {
       if (!r.ok) throw new Error(`HTTP ${r.status}`);
+      if (r.url && new URL(r.url).origin !== new URL(url).origin) return null;
       if (r.headers.get("silcrow-full-reload") === "true") return null;
       const contentType = r.headers.get("Content-Type") || "";
       const cacheControl = r.headers.get("silcrow-cache");
-      return r.text().then((text) => ({text, contentType, cacheControl}));
+      const transition = r.headers.get("silcrow-transition");
+      const scroll = r.headers.get("silcrow-scroll");
+      const nonce = r.headers.get("silcrow-nonce");
+      return r.text().then((text) => ({text, contentType, cacheControl, transition, scroll, nonce}));
     })
     .then((entry) => {
       if (!entry) return;
-      const {text, contentType, cacheControl} = entry;
+      const {text, contentType, cacheControl, transition, scroll, nonce} = entry;
       if (cacheControl !== "no-cache") {
-        cacheSet(url, {text, contentType, ts: Date.now()});
+        cacheSet(key, {text, contentType, ts: Date.now(), transition, scroll, nonce});
       }
     })
     .catch(() => {})
-    .finally(() => preloadInflight.delete(url));
-  preloadInflight.set(url, promise);
+    .finally(() => preloadInflight.delete(key));
+  preloadInflight.set(key, promise);
 }
 
 function onMouseEnter(e) {
@@ -2264,6 +2422,187 @@ function onMouseEnter(e) {
   }
 }
 
+// /drafts.js
+// ════════════════════════════════════════════════════════════
+// Drafts — auto-saved form state (s-autosave)
+// ════════════════════════════════════════════════════════════
+// Forms marked s-autosave persist their values to localStorage
+// while the user types and restore them on the next load. The
+// attribute value is the form's schema version: a stored draft
+// whose version differs is discarded instead of restored. Drafts
+// are cleared once a Silcrow-handled submit succeeds.
+
+const DRAFT_PREFIX = "silcrow:draft:";
+const DRAFT_DEBOUNCE = 400;
+const DRAFT_TTL = 24 * 60 * 60 * 1000;
+const DRAFT_SKIP_TYPES = new Set([
+  "button", "file", "hidden", "image", "password", "reset", "submit",
+]);
+const draftTimers = new WeakMap();
+const restoredDrafts = new WeakSet();
+let restoringDraft = false;
+
+function draftKey(form) {
+  if (!form.id) {
+    warn("s-autosave requires an id on the form");
+    return null;
+  }
+  return DRAFT_PREFIX + location.pathname + "#" + form.id;
+}
+
+// Fields the browser is told not to remember (autocomplete="off"),
+// payment card fields and one-time codes are never written to storage.
+function isSensitiveField(el) {
+  const tokens = String(el.getAttribute("autocomplete") || "").toLowerCase().split(/\s+/);
+  return tokens.some(t => t === "off" || t === "one-time-code" || t.startsWith("cc-"));
+}
+
+function isDraftField(el) {
+  if (!el.name || el.disabled) return false;
+  if (!("value" in el) || el.tagName === "BUTTON") return false;
+  if (DRAFT_SKIP_TYPES.has(String(el.type || "").toLowerCase())) return false;
+  return !isSensitiveField(el);
+}
+
+function serializeDraft(form) {
+  const values = {};
+  for (const el of form.elements) {
+    if (!isDraftField(el)) continue;
+    if (!Object.prototype.hasOwnProperty.call(values, el.name)) values[el.name] = [];
+    if (el.type === "checkbox" || el.type === "radio") {
+      if (el.checked) values[el.name].push(el.value);
+    } else if (el.tagName === "SELECT" && el.multiple) {
+      for (const opt of el.selectedOptions) values[el.name].push(opt.value);
+    } else {
+      values[el.name].push(el.value);
+    }
+  }
+  return values;
+}
+
+function saveDraft(form, key) {
+  if (!key || !form.isConnected) return;
+  const entry = {
+    v: form.getAttribute("s-autosave") || "",
+    ts: Date.now(),
+    values: serializeDraft(form),
+  };
+  try {
+    localStorage.setItem(key, JSON.stringify(entry));
+  } catch (e) {
+    warn("Failed to save draft: " + e.message);
+  }
+}
+
+function restoreDraft(form) {
+  if (restoredDrafts.has(form)) return;
+  restoredDrafts.add(form);
+
+  const key = draftKey(form);
+  if (!key) return;
+
+  let entry = null;
+  try {
+    const raw = localStorage.getItem(key);
+    if (raw) entry = JSON.parse(raw);
+  } catch (e) {
+    warn("Failed to read draft: " + e.message);
+  }
+  if (!entry || typeof entry !== "object" || !entry.values) return;
+
+  if (
+    entry.v !== (form.getAttribute("s-autosave") || "") ||
+    !(Date.now() - entry.ts < DRAFT_TTL)
+  ) {
+    clearDraft(form);
+    return;
+  }
+
+  const values = entry.values;
+  const consumed = {};
+  const changed = [];
+  for (const el of form.elements) {
+    if (!isDraftField(el)) continue;
+    if (!Object.prototype.hasOwnProperty.call(values, el.name)) continue;
+    const saved = Array.isArray(values[el.name]) ? values[el.name].map(String) : [];
+
+    if (el.type === "checkbox" || el.type === "radio") {
+      const next = saved.includes(el.value);
+      if (el.checked !== next) { el.checked = next; changed.push(el); }
+    } else if (el.tagName === "SELECT" && el.multiple) {
+      let dirty = false;
+      for (const opt of el.options) {
+        const next = saved.includes(opt.value);
+        if (opt.selected !== next) { opt.selected = next; dirty = true; }
+      }
+      if (dirty) changed.push(el);
+    } else {
+      // Repeated names (e.g. several text inputs named "tags") restore in order
+      const idx = consumed[el.name] || 0;
+      if (idx < saved.length && el.value !== saved[idx]) {
+        el.value = saved[idx];
+        changed.push(el);
+      }
+      consumed[el.name] = idx + 1;
+    }
+  }
+
+  // Let s-bind/framework listeners see restored values as user edits.
+  // onDraftInput ignores these so restoring doesn't re-save (and re-date)
+  // the draft.
+  restoringDraft = true;
+  try {
+    for (const el of changed) {
+      el.dispatchEvent(new Event("input", {bubbles: true}));
+      el.dispatchEvent(new Event("change", {bubbles: true}));
+    }
+  } finally {
+    restoringDraft = false;
+  }
+
+  form.dispatchEvent(new CustomEvent("silcrow:draft:restore", {
+    bubbles: true,
+    detail: {form, savedAt: entry.ts},
+  }));
+}
+
+function clearDraft(form) {
+  const timer = draftTimers.get(form);
+  if (timer) {
+    clearTimeout(timer);
+    draftTimers.delete(form);
+  }
+  const key = draftKey(form);
+  if (!key) return;
+  try {
+    localStorage.removeItem(key);
+  } catch (e) {}
+}
+
+function initDrafts(root) {
+  root.querySelectorAll("form[s-autosave]").forEach(restoreDraft);
+}
+
+function onDraftInput(e) {
+  if (restoringDraft) return;
+  if (!e.target || typeof e.target.closest !== "function") return;
+  const form = e.target.form || e.target.closest("form");
+  if (!form || !form.hasAttribute("s-autosave")) return;
+  if (!isDraftField(e.target)) return;
+
+  // Resolve the key now: a boosted navigation inside the debounce window
+  // would otherwise file this form's draft under the next page's path.
+  const key = draftKey(form);
+  if (!key) return;
+
+  const prev = draftTimers.get(form);
+  if (prev) clearTimeout(prev);
+  draftTimers.set(form, setTimeout(function () {
+    draftTimers.delete(form);
+    saveDraft(form, key);
+  }, DRAFT_DEBOUNCE));
+}
+
 // /optimistic.js
 // ════════════════════════════════════════════════════════════
 // Optimistic — snapshot & revert for instant UI feedback
@@ -2330,10 +2669,14 @@ function init() {
   window.addEventListener("popstate", onPopState);
   document.addEventListener("mouseenter", onMouseEnter, true);
   document.addEventListener("silcrow:sse", onSSEEvent);
+  document.addEventListener("input", onDraftInput);
+  document.addEventListener("change", onDraftInput);
 
   if (!history.state?.silcrow) {
-    history.replaceState({silcrow: true, url: location.href}, "", location.href);
+    history.replaceState({silcrow: true, url: location.href, idx: 0}, "", location.href);
   }
+  historyIdx = typeof history.state.idx === "number" ? history.state.idx : 0;
+  historyTransition = history.state.transition || null;
 
   // 0. SSR hydration seed — populates route atoms + prefetch cache
   // before any framework adapter subscribes, so React's getServerSnapshot
@@ -2346,7 +2689,10 @@ function init() {
   // 1b. Vanilla scope bindings (s-bind="scope")
   initScopeBindings();
 
-  // 1c. Pilcrow live-prop patch events — updates [data-pilcrow-live-field] text nodes.
+  // 1c. Restore auto-saved form drafts (s-autosave)
+  initDrafts(document);
+
+  // 1d. Pilcrow live-prop patch events — updates [data-pilcrow-live-field] text nodes.
   // Delegates to window.__pilcrow_live_patch if defined (injected by Pilcrow's head shim),
   // otherwise falls back to direct DOM patching so s-boost navigation also works.
   document.addEventListener("silcrow:sse:live", function (e) {
@@ -2417,6 +2763,8 @@ function destroy() {
   window.removeEventListener("popstate", onPopState);
   document.removeEventListener("mouseenter", onMouseEnter, true);
   document.removeEventListener("silcrow:sse", onSSEEvent);
+  document.removeEventListener("input", onDraftInput);
+  document.removeEventListener("change", onDraftInput);
 
   if (liveObserver) {
     liveObserver.disconnect();
@@ -2441,11 +2789,12 @@ window.Silcrow = {
 
   // --- Navigation (Unified ":" Placeholders) ---
   go(path, options = {}) {
-    return navigate(path, {
+    return navigateSameOrigin(path, {
       method: options.method || (options.body ? "POST" : "GET"),
       body: options.body || null,
       target: options.target ? document.querySelector(options.target) : null,
       skipHistory: options.skipHistory || false,
+      transition: options.transition || null,
       trigger: "api",
     });
   },
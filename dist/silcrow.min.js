@@ -1,3 +1,2251 @@
 // Silcrow.js — Hypermedia Runtime
-// Built: 2026-05-04T19:01:45.421Z
-!function(){"use strict";const t=document.body.hasAttribute("s-debug");function e(t){}const r=new Set(["http:","https:","mailto:","tel:"]),n=new Set(["action","background","cite","formaction","href","poster","src","xlink:href"]),o=/^data:image\/(?:avif|bmp|gif|jpe?g|png|webp);base64,[a-z0-9+/]+=*$/i;function s(t,e){const n=String(t||"").trim();if(!n)return!0;const s=n.replace(/[\u0000-\u0020\u007F]+/g,"");if(/^(?:javascript|vbscript|file):/i.test(s))return!1;if(/^data:/i.test(s))return e&&o.test(s);try{const t=new URL(n,location.origin);return r.has(t.protocol)}catch(t){return!1}}function c(t){const e=String(t||"").split(",");for(const t of e){const e=t.trim();if(!e)continue;const r=e.search(/\s/);if(!s(-1===r?e:e.slice(0,r),!1))return!1}return!0}const i=new Set(["base","embed","frame","iframe","link","meta","object","script","style"]);function a(t){if("A"!==t.tagName)return;if("_blank"!==String(t.getAttribute("target")||"").toLowerCase())return;const e=new Set(String(t.getAttribute("rel")||"").toLowerCase().split(/\s+/).filter(Boolean));e.add("noopener"),e.add("noreferrer"),t.setAttribute("rel",Array.from(e).join(" "))}function l(t,e={}){for(const r of i)if("style"!==r||!e.allowStyleTags)for(const e of t.querySelectorAll(r))e.remove();for(const e of t.querySelectorAll("*"))if("http://www.w3.org/1999/xhtml"===e.namespaceURI){for(const t of[...e.attributes]){const r=t.name.toLowerCase(),o=t.value;r.startsWith("on")||"style"===r||"srcdoc"===r?e.removeAttribute(t.name):"srcset"!==r||c(o)?n.has(r)&&(s(o,"src"===r&&"IMG"===e.tagName)||e.removeAttribute(t.name)):e.removeAttribute(t.name)}a(e)}else e.remove();for(const r of t.querySelectorAll("template"))l(r.content,e)}function u(t,e,r={}){const n=null==e?"":String(e);if(t.setHTML&&!r.allowStyleTags)return void t.setHTML(n);const o=(new DOMParser).parseFromString(n,"text/html");l(o.body,r),t.innerHTML=o.body.innerHTML}let f=null;function d(t,e=null){if(f)if(t&&e&&e._toasts)e._toasts.forEach(t=>f(t.message,t.level)),delete e._toasts,void 0!==e.data&&1===Object.keys(e).length&&(Object.assign(e,e.data),delete e.data);else if(!t){const t=document.cookie.match(new RegExp("(^|;\\s*)silcrow_toasts=([^;]+)"));if(t){try{const e=decodeURIComponent(t[2]);JSON.parse(e).forEach(t=>f(t.message,t.level))}catch(t){}document.cookie="silcrow_toasts=; Max-Age=0; path=/"}}}const p=new Set(["__proto__","constructor","prototype"]);function h(t){if(null===t||"object"!=typeof t)return!1;if(Array.isArray(t))return!0;const e=Object.getPrototypeOf(t);return e===Object.prototype||null===e}function b(t,e){if(Object.is(e,t))return t;if(!h(t)||!h(e))return e;if(Array.isArray(t)!==Array.isArray(e))return e;const r=Array.isArray(t)?t.slice():Object.assign({},t);let n=!1;for(const o in e){if(!Object.prototype.hasOwnProperty.call(e,o))continue;if(p.has(o))continue;const s=b(t[o],e[o]);Object.is(s,t[o])||(r[o]=s,n=!0)}return n?r:t}function g(t){let e=t;const r=new Set;function n(){for(const t of r)try{t(e)}catch(t){}}return{get:()=>e,set(t){Object.is(t,e)||(e=t,n())},patch(t){const r=b(e,t);Object.is(r,e)||(e=r,n())},subscribe:t=>(r.add(t),function(){r.delete(t)}),_subCount:()=>r.size}}const m=new Map,y=new Map,w=new Map;function v(t,e,r){let n=t.get(e);return n||(n=g(r),t.set(e,n)),n}function S(t,e){if("string"!=typeof t||!t)return null;if(t.startsWith("route:")){const r=t.slice(6);return r?e?v(m,r,void 0):m.get(r)||null:null}if(t.startsWith("stream:")){const r=t.slice(7);return r?e?v(y,r,void 0):y.get(r)||null:null}return e?v(w,t,void 0):w.get(t)||null}const E=new Map,A=new WeakMap;function k(t){const e=A.get(t);if(e){for(const t of e)try{t()}catch(t){}A.delete(t)}}const T=new WeakMap,O=(new WeakSet,new WeakMap),j=new WeakMap,L=[],C=/^\.?[A-Za-z0-9_-]+(\.[A-Za-z0-9_-]+)*$/,_={value:"string",checked:"boolean",disabled:"boolean",selected:"boolean",hidden:"boolean",required:"boolean",readOnly:"boolean",src:"string",href:"string",selectedIndex:"number"},U=new Set(["href","src","action","formaction","xlink:href","poster","cite","background"]),q=new Set(["__proto__","constructor","prototype"]);function M(t,e){if("object"!=typeof t||null===t)return;if(r=e,!C.test(r))return;var r;const n=e.split(".");let o=t;for(const t of n){if(q.has(t))return;if(!Object.prototype.hasOwnProperty.call(o,t))return;if(o=o[t],null==o)return n.indexOf(t)===n.length-1?o:void 0}return o}function x(t){return"string"==typeof t?document.querySelector(t)||document.body:t||document.body}function P(t){if(null===t||"object"!=typeof t)return String(t);let e=j.get(t);return e||(e=crypto.randomUUID(),j.set(t,e)),e}function N(t){try{return structuredClone(t)}catch{return JSON.parse(JSON.stringify(t))}}function R(e,r,n){if(isOnHandler(r))return void function(e){if(t)throw new Error("[silcrow] "+e)}("Binding to event handler attribute rejected: "+r);if(null===r){if(n&&"object"==typeof n&&!Array.isArray(n)){for(const t in n)R(e,t,n[t]);return}return void(e.textContent=null==n?"":String(n))}if("text"===r)return void(e.textContent=null==n?"":String(n));if("show"===r)return void(e.style.display=n?"":"none");if("class"===r){if(n&&"object"==typeof n&&!Array.isArray(n))for(const[t,r]of Object.entries(n))e.classList.toggle(t,!!r);else e.setAttribute("class",null==n?"":String(n));return}if("style"===r){if(n&&"object"==typeof n&&!Array.isArray(n))for(const[t,r]of Object.entries(n))e.style[t]=null==r?"":String(r);else e.setAttribute("style",null==n?"":String(n));return}const o=String(r).toLowerCase();if(U.has(o)&&(s(n,"src"===o&&"IMG"===e.tagName)||(n="")),null!=n)r in _?e[r]=n:!1===n?e.removeAttribute(r):!0===n?e.setAttribute(r,""):e.setAttribute(r,String(n));else if(r in _){const t=_[r];e[r]="boolean"!==t&&("number"===t?0:"")}else e.removeAttribute(r)}function W(t){const r=t.getAttribute("s-use");if(r)return{path:r,prop:null};for(const r of t.attributes)if(r.name.startsWith(":")&&":key"!==r.name){const t=r.name.slice(1);if(t.startsWith("on")||"style"===t||"srcdoc"===t){e();continue}return{path:r.value,prop:t}}return null}function z(t,e=null){const r=new Map,n="[s-use], [\\:text], [\\:class], [\\:style], [\\:show], [\\:value], [\\:disabled], [\\:hidden]",o=[];t.matches&&t.matches(n)&&o.push(t),o.push(...t.querySelectorAll(n));for(const t of o){if(t.closest("template"))continue;const n=W(t);if(!n)continue;const{path:o,prop:s}=n;if(e&&o.startsWith(e+".")){const n=o.substring(e.length+1);r.has(n)||r.set(n,[]),r.get(n).push({el:t,prop:s})}else e||(r.has(o)||r.set(o,[]),r.get(o).push({el:t,prop:s}))}return r}function H(t,e,r){let n=O.get(t);n||(n=z(t,r),O.set(t,n));for(const t in e){const r=n.get(t);r&&r.forEach(r=>R(r.el,r.prop,e[t]))}}function J(t,r,n={}){const o=x(r);let s=t;try{s=L.reduce((t,e)=>e(N(t))??t,N(t))}catch(e){s=t}s?._toasts&&d(!0,s),void 0===s?.data||1!==Object.keys(s).length||"object"!=typeof s.data||null===s.data||Array.isArray(s.data)||(s=s.data);let c=T.get(o);c&&!n.invalidate||(c=function(t){const e=[];return t.querySelectorAll("template[s-for]").forEach(t=>{const r=function(t){const e=t.match(/^\s*([A-Za-z0-9_-]+)\s+in\s+([A-Za-z0-9_-]+(?:\.[A-Za-z0-9_-]+)*)\s*$/);return e?{alias:e[1],path:e[2]}:null}(t.getAttribute("s-for")),n=t.getAttribute(":key"),o=n?.startsWith(r.alias+".")?n.substring(r.alias.length+1):n;e.push({path:r.path,tpl:t,alias:r.alias,keyPath:o})}),{scalars:z(t),collections:e}}(o),T.set(o,c));for(const[t,e]of c.scalars.entries()){const r=M(s,t);void 0!==r&&e.forEach(t=>R(t.el,t.prop,r))}c.collections.forEach(t=>{const r=M(s,t.path);Array.isArray(r)?function(t,r,n,o,s){const c=new Map;for(const e of t.children){const t=e.getAttribute(":key");t&&(c.has(t)||c.set(t,[]),c.get(t).push(e))}const i=new Set;let a=r;for(const t of n){const n=String(s?M(t,s):P(t));if(i.has(n)){e();continue}i.add(n);let l=c.get(n);if(!l){const t=r.content.cloneNode(!0);l=Array.from(t.children).filter(t=>1===t.nodeType),l.forEach(t=>t.setAttribute(":key",n))}l.forEach(e=>{H(e,t,o),a.nextElementSibling!==e&&a.after(e),a=e})}for(const[t,e]of c)i.has(t)||e.forEach(t=>t.remove())}(t.tpl.parentElement,t.tpl,r,t.alias,t.keyPath):r&&"object"==typeof r&&t.keyPath&&function(t,e,r,n,o){const s=String(M(r,o));if(!s)return;if(r._remove){for(const e of[...t.children])e.getAttribute(":key")===s&&e.remove();return}const c=[];for(const e of t.children)e.getAttribute(":key")===s&&c.push(e);if(c.length>0)c.forEach(t=>H(t,r,n));else{const o=e.content.cloneNode(!0);Array.from(o.children).filter(t=>1===t.nodeType).forEach(e=>{e.setAttribute(":key",s),H(e,r,n),t.appendChild(e)})}}(t.tpl.parentElement,t.tpl,r,t.alias,t.keyPath)}),o.dispatchEvent(new CustomEvent("silcrow:patched",{bubbles:!0,detail:{paths:Array.from(c.scalars.keys()),target:o}}))}function D(t){const e=x(t);T.delete(e),e.querySelectorAll("[\\:key]").forEach(t=>O.delete(t))}const G=new Map,I=new Map,$=new Map,F=new Set(["http:","https:"]);function Z(t){if("string"!=typeof t)return null;const e=t.trim();if(!e)return null;let r;try{r=new URL(e,location.origin)}catch(t){return null}return F.has(r.protocol)?r.origin!==location.origin?(r.href,null):r.href:(r.href,null)}function K(t){G.get(t.element)===t&&G.delete(t.element);const e=I.get(t.url);e&&(e.delete(t),0===e.size&&I.delete(t.url))}function Y(t){t.paused=!0,"ws"!==t.protocol&&t.hub&&(t.paused=!0,t.hub.paused=!0,t.hub.reconnectTimer&&(clearTimeout(t.hub.reconnectTimer),t.hub.reconnectTimer=null),t.hub.es&&(t.hub.es.close(),t.hub.es=null))}function B(t){if("string"==typeof t){if(t.startsWith("/")||t.startsWith("http://")||t.startsWith("https://")){const e=new URL(t,location.origin).href;let r=I.get(e);if(!r||0===r.size){const t=e.replace(/^http(s?)/,"ws$1");r=I.get(t)}return r?Array.from(r):[]}const e=document.querySelector(t);if(!e)return[];const r=G.get(e);return r?[r]:[]}if(!t)return[];const e=G.get(t);return e?[e]:[]}function Q(t){const e=t?.detail?.path;e&&"string"==typeof e&&X(t?.detail?.target||document.body,e)}function V(t){t.subscribers.size>0||(t.reconnectTimer&&(clearTimeout(t.reconnectTimer),t.reconnectTimer=null),t.es&&(t.es.close(),t.es=null),$.delete(t.url))}function X(t,e){const r="string"==typeof t?document.querySelector(t):t;if(!r)return;const n=Z(e);if(!n)return;const o=G.get(r);o&&"ws"!==o.protocol&&function(t){const e=G.get(t);if(!e||"ws"===e.protocol)return;const r=e.hub;r&&(r.subscribers.delete(t),0===r.subscribers.size&&V(r)),G.get(t)===e&&G.delete(t);const n=I.get(e.url);n&&(n.delete(e),0===n.size&&I.delete(e.url))}(r);const s=function(t){let e=$.get(t);return e||(e=function(t){return{url:t,es:null,subscribers:new Set,backoff:1e3,paused:!1,reconnectTimer:null}}(t),$.set(t,e)),e}(n);s.subscribers.add(r);const c={url:n,element:r,paused:!1,protocol:"sse",hub:s};G.set(r,c);let i=I.get(n);i||(i=new Set,I.set(n,i)),i.add(c),tt(s)}function tt(t){if(t.paused||0===t.subscribers.size)return;if(t.es&&t.es.readyState<EventSource.CLOSED)return;const e=new EventSource(t.url);t.es=e,e.onopen=function(){t.backoff=1e3,t.subscribers.forEach(function(e){document.dispatchEvent(new CustomEvent("silcrow:live:connect",{bubbles:!0,detail:{root:e,url:t.url,protocol:"sse"}}))})},e.onmessage=function(e){try{const r=JSON.parse(e.data);!function(t,e){if(t&&"object"==typeof t&&!Array.isArray(t)&&Object.prototype.hasOwnProperty.call(t,"target")){if(!Object.prototype.hasOwnProperty.call(t,"data"))return;const o=(n=e,"string"==typeof(r=t.target)&&r?document.querySelector(r)||null:n);return void(o&&J(t.data,o))}var r,n;J(t,e)}(r,t.subscribers.size>0?t.subscribers.values().next().value:document.body);const n=r&&"object"==typeof r&&!Array.isArray(r)&&Object.prototype.hasOwnProperty.call(r,"target")&&Object.prototype.hasOwnProperty.call(r,"data")?r.data:r;v(y,t.url,void 0).patch(n)}catch(t){t.message}},e.addEventListener("patch",function(e){try{const r=JSON.parse(e.data);let n=null,o=r;r&&"object"==typeof r&&!Array.isArray(r)&&Object.prototype.hasOwnProperty.call(r,"target")&&(o=r.data,r.target&&(n=document.querySelector(r.target))),!n&&t.subscribers.size>0&&(n=t.subscribers.values().next().value),n&&void 0!==o&&J(o,n),void 0!==o&&v(y,t.url,void 0).patch(o)}catch(t){t.message}}),e.addEventListener("html",function(e){try{const r=JSON.parse(e.data),n=r.target?document.querySelector(r.target):t.subscribers.size>0?t.subscribers.values().next().value:null;n&&Object.prototype.hasOwnProperty.call(r,"html")&&u(n,null==r.html?"":String(r.html))}catch(t){t.message}}),e.addEventListener("invalidate",function(e){const r=e.data?e.data.trim():null;if(r){const t=document.querySelector(r);t&&D(t)}else t.subscribers.forEach(function(t){D(t)})}),e.addEventListener("navigate",function(t){t.data&&Tt(t.data.trim(),{trigger:"sse"})}),e.addEventListener("custom",function(e){try{const r=JSON.parse(e.data);document.dispatchEvent(new CustomEvent("silcrow:sse:"+(r.event||"custom"),{bubbles:!0,detail:{url:t.url,data:r.data}}))}catch(t){t.message}}),e.onerror=function(){if(e.close(),t.es=null,t.paused||0===t.subscribers.size)return void(0===t.subscribers.size&&V(t));const r=t.backoff;t.subscribers.forEach(function(e){document.dispatchEvent(new CustomEvent("silcrow:live:disconnect",{bubbles:!0,detail:{root:e,url:t.url,protocol:"sse",reconnectIn:r}}))}),t.reconnectTimer=setTimeout(function(){t.reconnectTimer=null,tt(t)},r),t.backoff=Math.min(2*t.backoff,3e4)}}const et=new Map;function rt(t){let e=et.get(t);return e||(e=function(t){return{url:t,socket:null,subscribers:new Set,backoff:1e3,paused:!1,reconnectTimer:null}}(t),et.set(t,e)),e}function nt(t){t.subscribers.size>0||(t.reconnectTimer&&(clearTimeout(t.reconnectTimer),t.reconnectTimer=null),t.socket&&(t.socket.close(),t.socket=null),et.delete(t.url))}function ot(t){if(t.paused)return;if(t.socket&&t.socket.readyState<=WebSocket.OPEN)return;const e=new WebSocket(t.url);t.socket=e,e.onopen=function(){t.backoff=1e3,document.dispatchEvent(new CustomEvent("silcrow:live:connect",{bubbles:!0,detail:{url:t.url,protocol:"ws",subscribers:Array.from(t.subscribers)}}))},e.onmessage=function(e){!function(t,e){try{const r=JSON.parse(e),n=r&&r.type;let o;if(r.target){const t=document.querySelector(r.target);o=t?[t]:[]}else o=t.subscribers;if("patch"===n){if(void 0!==r.data){for(const t of o)J(r.data,t);v(y,t.url,void 0).patch(r.data)}}else if("html"===n)for(const t of o)u(t,null==r.markup?"":String(r.markup));else if("invalidate"===n)for(const t of o)D(t);else"navigate"===n?r.path&&Tt(r.path.trim(),{trigger:"ws"}):"custom"===n&&document.dispatchEvent(new CustomEvent("silcrow:ws:"+(r.event||"message"),{bubbles:!0,detail:{url:t.url,data:r.data}}))}catch(t){t.message}}(t,e.data)},e.onclose=function(){if(t.socket=null,t.paused)return;if(0===t.subscribers.size)return void nt(t);const e=t.backoff;document.dispatchEvent(new CustomEvent("silcrow:live:disconnect",{bubbles:!0,detail:{url:t.url,protocol:"ws",reconnectIn:e,subscribers:Array.from(t.subscribers)}})),t.reconnectTimer=setTimeout(function(){t.reconnectTimer=null,ot(t)},e),t.backoff=Math.min(2*t.backoff,3e4)},e.onerror=function(){}}function st(t){const e=G.get(t);if(!e||"ws"!==e.protocol)return;const r=e.hub;r&&(r.subscribers.delete(t),0===r.subscribers.size&&nt(r)),K(e)}function ct(t,e){const r="string"==typeof t?document.querySelector(t):t;if(!r)return;const n=function(t){if("string"!=typeof t)return null;const e=t.trim();if(!e)return null;let r;try{r=new URL(e,location.origin)}catch(t){return null}if("https:"===r.protocol?r.protocol="wss:":"http:"===r.protocol&&(r.protocol="ws:"),"ws:"!==r.protocol&&"wss:"!==r.protocol)return r.href,null;const n=location.origin.replace(/^http(s?)/,"ws$1");return r.origin!==n?(r.href,null):r.href}(e);if(!n)return;const o=G.get(r);o&&"ws"===o.protocol?st(r):o&&(Y(o),K(o));const s=rt(n);s.subscribers.add(r),function(t){G.set(t.element,t);let e=I.get(t.url);e||(e=new Set,I.set(t.url,e)),e.add(t)}({es:null,socket:null,url:n,element:r,backoff:0,paused:!1,reconnectTimer:null,protocol:"ws",hub:s}),ot(s)}const it=["s-get","s-post","s-put","s-delete","s-patch"],at=it.map(function(t){return"["+t+"]"}).join(","),lt=it.map(function(t){return"form["+t+"]"}).join(","),ut=3e4,ft=3e5,dt=50,pt=new WeakMap;let ht=null,bt=null;const gt=new Map,mt=new Map;function yt(t){for(var e=0;e<it.length;e++){var r=t.getAttribute(it[e]);if(null!==r){if(r.includes(":key")){var n=t.closest("[:key]");if(n){var o=n.getAttribute(":key");r=r.replace(/:key/g,o)}}try{return{url:new URL(r,location.origin).href,method:it[e].slice(2).toUpperCase()}}catch(t){return null}}}return null}function wt(t){let e=t.getAttribute("s-target");if(e){if(e.includes(":key")){const r=t.closest("[:key]");r&&(e=e.replace(/:key/g,r.getAttribute(":key")))}const r=document.querySelector(e);if(r)return r}const r=t.closest("[:key]");if(r){const t=r.parentElement;return t&&t.querySelector("template[s-for]")?t:r}return t}function vt(t){const e=t.getAttribute("href");if(!e||e.startsWith("#"))return!1;if(t.hasAttribute("download"))return!1;if("_blank"===t.getAttribute("target"))return!1;try{return new URL(e,location.origin).origin===location.origin}catch(t){return!1}}function St(t){const e=t.getAttribute("s-target");if(e){const t=document.querySelector(e);if(t)return t}return document.body}function Et(t){t.classList.remove("silcrow-loading"),t.removeAttribute("aria-busy")}function At(t,e){if(gt.set(t,e),gt.size>dt){const t=gt.keys().next().value;gt.delete(t)}}function kt(){gt.clear(),E.clear()}async function Tt(t,e={}){const{method:r="GET",body:n=null,target:o=null,trigger:s="click",skipHistory:c=!1,sourceEl:i=null}=e,a=new URL(t,location.origin).href;let l=o||document.body;const f=i?.getAttribute("s-target")||null,p=!c&&!f&&"GET"===r,h=new CustomEvent("silcrow:navigate",{bubbles:!0,cancelable:!0,detail:{url:a,method:r,trigger:s,target:l}});if(!document.dispatchEvent(h))return;const b=pt.get(l);b&&"GET"===b.method&&b.controller.abort();const g=new AbortController;pt.set(l,{controller:g,method:r});const y=function(t){const e=t?.getAttribute("s-timeout");return e?parseInt(e,10):ut}(i);let w=!1;const S=setTimeout(()=>{w=!0,g.abort()},y);var A;(A=l).classList.add("silcrow-loading"),A.setAttribute("aria-busy","true");try{let t,e,o="GET"===r?function(t){const e=gt.get(t);return e?Date.now()-e.ts>ft?(gt.delete(t),null):e:null}(a):null,c=!1,h=a,b=null,y=null;const w=i?.hasAttribute("s-html");if(o)t=o.text,e=o.contentType;else{const o=function(t,e,r,n){const o={method:t,headers:{"silcrow-target":"true",Accept:r?"text/html":"application/json"},signal:n};return e&&(e instanceof FormData?o.body=e:e instanceof URLSearchParams?(o.headers["Content-Type"]="application/x-www-form-urlencoded",o.body=e):(o.headers["Content-Type"]="application/json",o.body=JSON.stringify(e))),o}(r,n,w,g.signal),s=await fetch(a,o);if(!s.ok)throw new Error(`HTTP ${s.status}: ${s.statusText}`);if("GET"===r&&!f&&"true"===s.headers.get("silcrow-full-reload"))return void window.location.assign(s.url||a);const i=function(t,e){const r={redirected:t.redirected,finalUrl:t.url||e,pushUrl:null,retargetSelector:null,sideEffects:{patch:t.headers.get("silcrow-patch"),invalidate:t.headers.get("silcrow-invalidate"),navigate:t.headers.get("silcrow-navigate"),sse:t.headers.get("silcrow-sse"),ws:t.headers.get("silcrow-ws")}},n=t.headers.get("silcrow-trigger");if(n)try{const t=JSON.parse(n);Object.entries(t).forEach(([t,e])=>{document.dispatchEvent(new CustomEvent(t,{bubbles:!0,detail:e}))})}catch(t){document.dispatchEvent(new CustomEvent(n,{bubbles:!0}))}return r.retargetSelector=t.headers.get("silcrow-retarget"),r.pushUrl=t.headers.get("silcrow-push"),r.pushUrl&&(r.finalUrl=new URL(r.pushUrl,location.origin).href,r.redirected=!0),r}(s,a);if(c=i.redirected,h=i.finalUrl,b=i.pushUrl,y=i.sideEffects,i.retargetSelector){const t=document.querySelector(i.retargetSelector);t&&(l=t)}t=await s.text(),e=s.headers.get("Content-Type")||"";const u=s.headers.get("silcrow-cache");"GET"!==r||c||"no-cache"===u||At(a,{text:t,contentType:e,ts:Date.now()}),"GET"!==r&&kt()}if(ht&&!1===await ht({url:a,finalUrl:h,redirected:c,method:r,trigger:s,response:t,contentType:e,target:l}))return void Et(l);if(p&&"popstate"!==s){const t=history.state||{};history.replaceState({...t,scrollY:window.scrollY},"",location.href)}const{swapContent:S,isJSON:A}=function(t,e,r){const n=e.includes("application/json");let o;return n?(o=JSON.parse(t),d(!0,o)):(o=function(t,e,r){const n=t.trimStart();if(n.startsWith("<!")||n.startsWith("<html")){const n=(new DOMParser).parseFromString(t,"text/html");if(r){const t=n.querySelector("title");t&&(document.title=t.textContent)}if(e){const t=n.querySelector(e);if(t)return t.innerHTML}return n.body.innerHTML}return t}(t,r,!r),d(!1)),{swapContent:o,isJSON:n}}(t,e,f);let k=!1;const T=()=>{k||(k=!0,A?J(S,l):u(l,S,{allowStyleTags:"GET"===r&&!f&&l===document.body}))},O=new CustomEvent("silcrow:before-swap",{bubbles:!0,cancelable:!0,detail:{url:h,target:l,content:S,isJSON:A,proceed:T}});if(!document.dispatchEvent(O))return;if(k||T(),A&&"GET"===r&&!f)try{const t=new URL(h).pathname;v(m,t,void 0).set(S),E.set(t,Promise.resolve(S))}catch(t){}!function(t){const{pushUrl:e,redirected:r,finalUrl:n,fullUrl:o,shouldPushHistory:s,trigger:c,targetSelector:i,targetEl:a,sideEffects:l}=t;!function(t,e){if(t){if(t.patch)try{const e=JSON.parse(t.patch);if(e&&"object"==typeof e&&e.target&&Object.prototype.hasOwnProperty.call(e,"data")){const t=document.querySelector(e.target);t&&J(e.data,t)}}catch(t){t.message}if(t.invalidate){const e=document.querySelector(t.invalidate);e&&D(e)}if(t.navigate&&Tt(t.navigate,{trigger:"header"}),t.sse){const r=Z(t.sse);if(!r)return;document.dispatchEvent(new CustomEvent("silcrow:sse",{bubbles:!0,detail:{path:r,target:e||null}}))}t.ws&&ct(e||document.body,t.ws)}}(l,a);const u=e||(r?n:o);if(s&&"popstate"!==c&&history.pushState({silcrow:!0,url:u,targetSelector:i},"",u),"popstate"===c){const t=(history.state||{}).scrollY;window.scrollTo(0,t||0)}else s&&window.scrollTo(0,0);document.dispatchEvent(new CustomEvent("silcrow:load",{bubbles:!0,detail:{url:n,target:a,redirected:r}})),a&&a.querySelectorAll("[s-sse]").forEach(function(t){const e=t.getAttribute("s-sse");e&&X(t,e)})}({pushUrl:b,redirected:c,finalUrl:h,fullUrl:a,shouldPushHistory:p,trigger:s,targetSelector:f,targetEl:l,sideEffects:y})}catch(t){if("AbortError"===t.name){if(w){const t=new Error(`[silcrow] Request timed out after ${y}ms`);t.name="TimeoutError",document.dispatchEvent(new CustomEvent("silcrow:error",{bubbles:!0,detail:{error:t,url:a}})),bt&&bt(t,{url:a,method:r,trigger:s,target:l})}return}bt&&bt(t,{url:a,method:r,trigger:s,target:l}),document.dispatchEvent(new CustomEvent("silcrow:error",{bubbles:!0,detail:{error:t,url:a}}))}finally{clearTimeout(S),Et(l),pt.delete(l)}}async function Ot(t){if(t.metaKey||t.ctrlKey||t.shiftKey||t.altKey)return;if(0!==t.button)return;if(!t.target||"function"!=typeof t.target.closest)return;const e=t.target.closest(at);if(e&&"FORM"!==e.tagName){t.preventDefault();const r=yt(e);if(!r)return;const n=mt.get(r.url);return n&&await n,void Tt(r.url,{method:r.method,target:wt(e),skipHistory:e.hasAttribute("s-skip-history"),sourceEl:e,trigger:"click"})}const r=t.target.closest("a[href]");if(!r||!vt(r))return;const n=r.closest("[s-boost]");if(!n)return;t.preventDefault();const o=new URL(r.getAttribute("href"),location.origin).href,s=mt.get(o);s&&await s,Tt(o,{method:"GET",target:St(n),skipHistory:r.hasAttribute("s-skip-history"),sourceEl:r,trigger:"click"})}function jt(t){if(!t.target||"function"!=typeof t.target.closest)return;const e=t.target.closest(lt);if(!e)return;t.preventDefault();const r=yt(e);if(!r)return;const n=new FormData(e);if("GET"===r.method){const t=new URL(r.url,location.origin);for(const[e,r]of n)t.searchParams.append(e,r);Tt(t.href,{method:r.method,target:wt(e),sourceEl:e,trigger:"submit"})}else{const t=[...n.values()].some(t=>t instanceof File);Tt(r.url,{method:r.method,body:t?n:new URLSearchParams(n),target:wt(e),sourceEl:e,trigger:"submit"})}}function Lt(t){if(!t.state)return;const e=location.href,r=t.state.targetSelector;Tt(e,{method:"GET",target:(r?document.querySelector(r):document.body)||document.body,trigger:"popstate",skipHistory:!0})}function Ct(t,e){if(gt.has(t)||mt.has(t))return;const r=new AbortController,n=fetch(t,{headers:{"silcrow-target":"true",Accept:e?"text/html":"application/json"},signal:r.signal}).then(t=>{if(!t.ok)throw new Error(`HTTP ${t.status}`);if("true"===t.headers.get("silcrow-full-reload"))return null;const e=t.headers.get("Content-Type")||"",r=t.headers.get("silcrow-cache");return t.text().then(t=>({text:t,contentType:e,cacheControl:r}))}).then(e=>{if(!e)return;const{text:r,contentType:n,cacheControl:o}=e;"no-cache"!==o&&At(t,{text:r,contentType:n,ts:Date.now()})}).catch(()=>{}).finally(()=>mt.delete(t));mt.set(t,n)}function _t(t){if(!t.target||"function"!=typeof t.target.closest)return;const e=t.target.closest("[s-preload]");if(!e)return;const r=yt(e);r?Ct(r.url,e.hasAttribute("s-html")):"A"===e.tagName&&vt(e)&&e.closest("[s-boost]")&&Ct(new URL(e.getAttribute("href"),location.origin).href,e.hasAttribute("s-html"))}const Ut=new WeakMap;let qt=null,Mt=!1;function xt(){document.addEventListener("click",Ot),document.addEventListener("submit",jt),window.addEventListener("popstate",Lt),document.addEventListener("mouseenter",_t,!0),document.addEventListener("silcrow:sse",Q),history.state?.silcrow||history.replaceState({silcrow:!0,url:location.href},"",location.href),function(){if("undefined"==typeof window)return;const t=window.__silcrow_seed||window.__pilcrow_props;if(t&&"object"==typeof t)for(const e in t){if(!Object.prototype.hasOwnProperty.call(t,e))continue;if(p.has(e))continue;const r=t[e];let n=e;try{n=new URL(e,location.origin).pathname}catch(t){}v(m,n,void 0).set(r),E.set(n,Promise.resolve(r))}}(),document.querySelectorAll("[s-sse]").forEach(t=>{const e=t.getAttribute("s-sse");e&&X(t,e)}),document.querySelectorAll("[s-ws], [s-wss]").forEach(t=>{const e=t.getAttribute("s-ws")||t.getAttribute("s-wss");e&&ct(t,e)}),document.querySelectorAll("[s-bind]").forEach(function(t){const e=t.getAttribute("s-bind");e&&function(t,e){const r=S(e,!0);if(!r)return;const n=function(e){if(null!=e)try{J(e,t)}catch(t){t.message}};n(r.get());const o=r.subscribe(n);let s=A.get(t);s||(s=new Set,A.set(t,s)),s.add(o)}(t,e)}),document.addEventListener("silcrow:sse:live",function(t){const e=t.detail&&t.detail.data;e&&"object"==typeof e&&!Array.isArray(e)&&("function"==typeof window.__pilcrow_live_patch?window.__pilcrow_live_patch(e):Object.keys(e).forEach(function(t){const r=e[t];document.querySelectorAll('[data-pilcrow-live-field="'+t+'"]').forEach(function(t){t.textContent=null==r?"":String(r)})}))}),qt=new MutationObserver(function(t){function e(t){const e=G.get(t);e&&("ws"===e.protocol?st(t):(Y(e),K(e)))}for(const r of t)for(const t of r.removedNodes)if(1===t.nodeType&&(e(t),k(t),t.querySelectorAll)){for(const r of t.querySelectorAll("[s-sse], [s-ws], [s-wss]"))e(r);for(const e of t.querySelectorAll("[s-bind]"))k(e)}}),qt.observe(document.body,{childList:!0,subtree:!0}),window.__psData&&(J(window.__psData,document.body),delete window.__psData),Mt=!0}window.Silcrow={patch:J,invalidate:D,stream:function(t){let e=null;return function(r){e=r,queueMicrotask(()=>{e===r&&(J(e,t),e=null)})}},go:(t,e={})=>Tt(t,{method:e.method||(e.body?"POST":"GET"),body:e.body||null,target:e.target?document.querySelector(e.target):null,skipHistory:e.skipHistory||!1,trigger:"api"}),live:X,send:function(t,r){const n=B(r);if(!n.length)return;const o=new Set;for(const r of n){if("ws"!==r.protocol){e();continue}const n=r.hub;if(n&&!o.has(n))if(o.add(n),n.socket&&n.socket.readyState===WebSocket.OPEN)try{const e="string"==typeof t?t:JSON.stringify(t);n.socket.send(e)}catch(t){e(t.message)}else e()}},disconnect:function(t){const e=B(t);if(e.length)for(const t of e)Y(t)},reconnect:function(t){const e=B(t);if(!e.length)return;const r=new Set;for(const t of e)if(t.paused=!1,"ws"===t.protocol){const e=rt(t.url);e.subscribers.add(t.element),t.hub=e,r.has(e)||(r.add(e),e.paused=!1,e.backoff=1e3,e.reconnectTimer&&(clearTimeout(e.reconnectTimer),e.reconnectTimer=null),ot(e))}else{const e=t.hub;if(!e)continue;t.paused=!1,e.paused=!1,e.backoff=1e3,e.reconnectTimer&&(clearTimeout(e.reconnectTimer),e.reconnectTimer=null),tt(e)}},prefetch:function(t){if("string"!=typeof t||!t)return Promise.reject(new Error("[silcrow] prefetch requires a string path"));const e=function(){try{return new URL(t,location.origin).pathname}catch(e){return t}}(),r=E.get(e);if(r)return r;const n=new URL(t,location.origin).href,o=fetch(n,{headers:{"silcrow-target":"true",Accept:"application/json"}}).then(function(t){if(!t.ok)throw new Error("HTTP "+t.status);return t.json()}).then(function(t){return t&&"object"==typeof t&&void 0!==t.data&&1===Object.keys(t).length&&"object"==typeof t.data&&null!==t.data&&!Array.isArray(t.data)&&(t=t.data),v(m,e,void 0).set(t),t}).catch(function(t){throw E.delete(e),t});return E.set(e,o),o},submit:async function(t,e,r){r=r||{};const n=new URL(t,location.origin).href,o=r.method||(e?"POST":"GET"),s={method:o,headers:{"silcrow-target":"true",Accept:"application/json"}};r.headers&&Object.assign(s.headers,r.headers),e&&(e instanceof FormData?s.body=e:e instanceof URLSearchParams?(s.headers["Content-Type"]="application/x-www-form-urlencoded",s.body=e):"string"==typeof e?s.body=e:(s.headers["Content-Type"]="application/json",s.body=JSON.stringify(e)));const c=await fetch(n,s),i=c.headers.get("Content-Type")||"",a=await c.text();if("GET"!==o){kt();const t=c.headers.get("silcrow-invalidate");t&&function(t){if(null==t)return void E.clear();let e=t;try{e=new URL(t,location.origin).pathname}catch(t){}E.delete(e)}(t)}let l=null;if(i.includes("application/json")&&a)try{l=JSON.parse(a),d(!0,l)}catch(t){}return r.scope&&null!==l&&S(r.scope,!0).set(l),{ok:c.ok,status:c.status,data:l,html:null===l?a:null,headers:c.headers}},subscribe(t,e){const r=S(t,!0);return r?r.subscribe(e):function(){}},snapshot(t){const e=S(t,!1);return e?e.get():void 0},publish(t,e){const r=S(t,!0);r&&r.patch(e)},optimistic:function(t,e){const r="string"==typeof e?document.querySelector(e):e;r&&(Ut.set(r,r.innerHTML),J(t,r),document.dispatchEvent(new CustomEvent("silcrow:optimistic",{bubbles:!0,detail:{root:r,data:t}})))},revert:function(t){const e="string"==typeof t?document.querySelector(t):t;if(!e)return;const r=Ut.get(e);void 0!==r&&(e.innerHTML=r,Ut.delete(e),D(e),document.dispatchEvent(new CustomEvent("silcrow:revert",{bubbles:!0,detail:{root:e}})))},onToast:t=>(function(t){f=t,d(!1)}(t),window.Silcrow),use(t){return Mt||"function"==typeof t&&L.push(t),this},onRoute:t=>(ht=t,window.Silcrow),onError:t=>(bt=t,window.Silcrow),destroy:function(){document.removeEventListener("click",Ot),document.removeEventListener("submit",jt),window.removeEventListener("popstate",Lt),document.removeEventListener("mouseenter",_t,!0),document.removeEventListener("silcrow:sse",Q),qt&&(qt.disconnect(),qt=null),gt.clear(),mt.clear(),function(){for(const t of G.values())"ws"!==t.protocol&&(t.paused=!0);G.clear(),I.clear();for(const t of $.values())t.reconnectTimer&&clearTimeout(t.reconnectTimer),t.es&&t.es.close();$.clear();for(const t of et.values())t.reconnectTimer&&clearTimeout(t.reconnectTimer),t.socket&&t.socket.close();et.clear()}(),m.clear(),y.clear(),w.clear(),E.clear()}},"loading"===document.readyState?document.addEventListener("DOMContentLoaded",xt):xt()}();
\ No newline at end of file
+// Built: 2026-10-15T18:14:43.000Z
+(function(){
+"use strict";
+const DEBUG = document.body.hasAttribute("s-debug");
+function warn(msg) {
+if (DEBUG) console.warn("[silcrow]", msg);
+}
+function throwErr(msg) {
+if (DEBUG) throw new Error("[silcrow] " + msg);
+}
+const URL_SAFE_PROTOCOLS = new Set(["http:", "https:", "mailto:", "tel:"]);
+const URL_ATTRS = new Set([
+"action",
+"background",
+"cite",
+"formaction",
+"href",
+"poster",
+"src",
+"xlink:href",
+]);
+const SAFE_DATA_IMAGE_RE =
+/^data:image\/(?:avif|bmp|gif|jpe?g|png|webp);base64,[a-z0-9+/]+=*$/i;
+function hasSafeProtocol(raw, allowDataImage) {
+const value = String(raw || "").trim();
+if (!value) return true;
+const compact = value.replace(/[\u0000-\u0020\u007F]+/g, "");
+if (/^(?:javascript|vbscript|file):/i.test(compact)) return false;
+if (/^data:/i.test(compact)) {
+return allowDataImage && SAFE_DATA_IMAGE_RE.test(compact);
+}
+try {
+const parsed = new URL(value, location.origin);
+return URL_SAFE_PROTOCOLS.has(parsed.protocol);
+} catch (e) {
+return false;
+}
+}
+function isSameOriginUrl(raw) {
+try {
+return new URL(String(raw || ""), location.origin).origin === location.origin;
+} catch (e) {
+return false;
+}
+}
+function hasSafeSrcSet(raw) {
+const parts = String(raw || "").split(",");
+for (const part of parts) {
+const candidate = part.trim();
+if (!candidate) continue;
+const idx = candidate.search(/\s/);
+const url = idx === -1 ? candidate : candidate.slice(0, idx);
+if (!hasSafeProtocol(url, false)) {
+return false;
+}
+}
+return true;
+}
+function extractHTML(html, targetSelector, isFullPage) {
+const trimmed = html.trimStart();
+if (trimmed.startsWith("<!") || trimmed.startsWith("<html")) {
+const parser = new DOMParser();
+const doc = parser.parseFromString(html, "text/html");
+if (isFullPage) {
+const title = doc.querySelector("title");
+if (title) document.title = title.textContent;
+}
+if (targetSelector) {
+const match = doc.querySelector(targetSelector);
+if (match) return match.innerHTML;
+}
+return doc.body.innerHTML;
+}
+return html;
+}
+const FORBIDDEN_HTML_TAGS = new Set([
+"base",
+"embed",
+"frame",
+"iframe",
+"link",
+"meta",
+"object",
+"script",
+"style",
+]);
+const CSP_NONCE =
+document.currentScript?.nonce ||
+document.querySelector('meta[name="csp-nonce"]')?.getAttribute("content") ||
+"";
+function hardenBlankTargets(node) {
+if (node.tagName !== "A") return;
+if (String(node.getAttribute("target") || "").toLowerCase() !== "_blank") return;
+const relTokens = new Set(
+String(node.getAttribute("rel") || "")
+.toLowerCase()
+.split(/\s+/)
+.filter(Boolean)
+);
+relTokens.add("noopener");
+relTokens.add("noreferrer");
+node.setAttribute("rel", Array.from(relTokens).join(" "));
+}
+function sanitizeTree(root, options = {}) {
+for (const tag of FORBIDDEN_HTML_TAGS) {
+if (tag === "style" && options.allowStyleTags) continue;
+for (const node of root.querySelectorAll(tag)) {
+node.remove();
+}
+}
+for (const node of root.querySelectorAll("*")) {
+if (node.namespaceURI !== "http://www.w3.org/1999/xhtml") {
+node.remove();
+continue;
+}
+for (const attr of [...node.attributes]) {
+const name = attr.name.toLowerCase();
+const value = attr.value;
+if (name.startsWith("on") || name === "style" || name === "srcdoc") {
+node.removeAttribute(attr.name);
+continue;
+}
+if (name === "srcset" && !hasSafeSrcSet(value)) {
+node.removeAttribute(attr.name);
+continue;
+}
+if (URL_ATTRS.has(name)) {
+const allowDataImage = name === "src" && node.tagName === "IMG";
+if (!hasSafeProtocol(value, allowDataImage)) {
+node.removeAttribute(attr.name);
+}
+}
+}
+hardenBlankTargets(node);
+}
+for (const tpl of root.querySelectorAll("template")) {
+sanitizeTree(tpl.content, options);
+}
+}
+function safeSetHTML(el, raw, options = {}) {
+const markup = raw == null ? "" : String(raw);
+if (el.setHTML && !options.allowStyleTags) {
+el.setHTML(markup);
+return;
+}
+const doc = new DOMParser().parseFromString(markup, "text/html");
+sanitizeTree(doc.body, options);
+if (options.allowStyleTags && CSP_NONCE) {
+const responseNonce = options.responseNonce || "";
+for (const style of doc.body.querySelectorAll("style")) {
+if (responseNonce && style.getAttribute("nonce") === responseNonce) {
+style.setAttribute("nonce", CSP_NONCE);
+} else {
+style.remove();
+}
+}
+}
+el.innerHTML = doc.body.innerHTML;
+}
+let toastHandler = null;
+function processToasts(isJSON, content = null) {
+if (!toastHandler) return;
+if (isJSON && content && content._toasts) {
+content._toasts.forEach(t => toastHandler(t.message, t.level));
+delete content._toasts;
+if (content.data !== undefined && Object.keys(content).length === 1) {
+Object.assign(content, content.data);
+delete content.data;
+}
+} else if (!isJSON) {
+const match = document.cookie.match(new RegExp('(^|;\\s*)silcrow_toasts=([^;]+)'));
+if (match) {
+try {
+const rawJSON = decodeURIComponent(match[2]);
+const toasts = JSON.parse(rawJSON);
+toasts.forEach(t => toastHandler(t.message, t.level));
+} catch (e) {
+console.error("Failed to parse toasts", e);
+}
+document.cookie = "silcrow_toasts=; Max-Age=0; path=/";
+}
+}
+}
+function setToastHandler(handler) {
+toastHandler = handler;
+processToasts(false);
+}
+const BLOCKED_ATOM_KEYS = new Set(["__proto__", "constructor", "prototype"]);
+function isPlainMergeable(v) {
+if (v === null || typeof v !== "object") return false;
+if (Array.isArray(v)) return true;
+const proto = Object.getPrototypeOf(v);
+return proto === Object.prototype || proto === null;
+}
+function mergePath(prev, next) {
+if (Object.is(next, prev)) return prev;
+if (!isPlainMergeable(prev) || !isPlainMergeable(next)) return next;
+if (Array.isArray(prev) !== Array.isArray(next)) return next;
+const out = Array.isArray(prev) ? prev.slice() : Object.assign({}, prev);
+let changed = false;
+for (const k in next) {
+if (!Object.prototype.hasOwnProperty.call(next, k)) continue;
+if (BLOCKED_ATOM_KEYS.has(k)) continue;
+const merged = mergePath(prev[k], next[k]);
+if (!Object.is(merged, prev[k])) {
+out[k] = merged;
+changed = true;
+}
+}
+return changed ? out : prev;
+}
+function createAtom(initial) {
+let value = initial;
+const subs = new Set();
+function notify() {
+for (const fn of subs) {
+try { fn(value); } catch (e) { console.error("[silcrow] atom subscriber threw", e); }
+}
+}
+return {
+get() { return value; },
+set(next) {
+if (Object.is(next, value)) return;
+value = next;
+notify();
+},
+patch(data) {
+const next = mergePath(value, data);
+if (Object.is(next, value)) return;
+value = next;
+notify();
+},
+subscribe(fn) {
+subs.add(fn);
+return function unsubscribe() { subs.delete(fn); };
+},
+_subCount() { return subs.size; },
+};
+}
+const routeAtoms = new Map();
+const streamAtoms = new Map();
+const scopeAtoms = new Map();
+function getOrCreateAtom(map, key, initial) {
+let atom = map.get(key);
+if (!atom) {
+atom = createAtom(initial);
+map.set(key, atom);
+}
+return atom;
+}
+function resolveAtomByScope(scope, createIfMissing) {
+if (typeof scope !== "string" || !scope) return null;
+if (scope.startsWith("route:")) {
+const key = scope.slice(6);
+if (!key) return null;
+return createIfMissing
+? getOrCreateAtom(routeAtoms, key, undefined)
+: routeAtoms.get(key) || null;
+}
+if (scope.startsWith("stream:")) {
+const key = scope.slice(7);
+if (!key) return null;
+return createIfMissing
+? getOrCreateAtom(streamAtoms, key, undefined)
+: streamAtoms.get(key) || null;
+}
+return createIfMissing
+? getOrCreateAtom(scopeAtoms, scope, undefined)
+: scopeAtoms.get(scope) || null;
+}
+const prefetchPromises = new Map();
+function prefetchRoute(path) {
+if (typeof path !== "string" || !path) {
+return Promise.reject(new Error("[silcrow] prefetch requires a string path"));
+}
+const key = (function() {
+try { return new URL(path, location.origin).pathname; }
+catch (e) { return path; }
+})();
+const existing = prefetchPromises.get(key);
+if (existing) return existing;
+const url = new URL(path, location.origin).href;
+const promise = fetch(url, {
+headers: {
+"silcrow-target": "true",
+"Accept": "application/json",
+},
+})
+.then(function (r) {
+if (!r.ok) throw new Error("HTTP " + r.status);
+return r.json();
+})
+.then(function (data) {
+if (
+data && typeof data === "object" &&
+data.data !== undefined &&
+Object.keys(data).length === 1 &&
+typeof data.data === "object" &&
+data.data !== null &&
+!Array.isArray(data.data)
+) {
+data = data.data;
+}
+getOrCreateAtom(routeAtoms, key, undefined).set(data);
+return data;
+})
+.catch(function (err) {
+prefetchPromises.delete(key);
+throw err;
+});
+prefetchPromises.set(key, promise);
+return promise;
+}
+function evictPrefetch(path) {
+if (path == null) {
+prefetchPromises.clear();
+return;
+}
+let key = path;
+try { key = new URL(path, location.origin).pathname; } catch (e) {}
+prefetchPromises.delete(key);
+}
+async function submitAction(url, body, options) {
+options = options || {};
+const fullUrl = new URL(url, location.origin).href;
+const method = options.method || (body ? "POST" : "GET");
+const opts = {
+method,
+headers: {
+"silcrow-target": "true",
+"Accept": "application/json",
+},
+};
+if (options.headers) Object.assign(opts.headers, options.headers);
+if (body) {
+if (body instanceof FormData) {
+opts.body = body;
+} else if (body instanceof URLSearchParams) {
+opts.headers["Content-Type"] = "application/x-www-form-urlencoded";
+opts.body = body;
+} else if (typeof body === "string") {
+opts.body = body;
+} else {
+opts.headers["Content-Type"] = "application/json";
+opts.body = JSON.stringify(body);
+}
+}
+const response = await fetch(fullUrl, opts);
+const contentType = response.headers.get("Content-Type") || "";
+const text = await response.text();
+if (method !== "GET") {
+bustCacheOnMutation();
+const inv = response.headers.get("silcrow-invalidate");
+if (inv) evictPrefetch(inv);
+}
+let parsed = null;
+if (contentType.includes("application/json") && text) {
+try {
+parsed = JSON.parse(text);
+processToasts(true, parsed);
+} catch (e) {
+warn("submit: invalid JSON response");
+}
+}
+if (options.scope && parsed !== null) {
+resolveAtomByScope(options.scope, true).set(parsed);
+}
+return {
+ok: response.ok,
+status: response.status,
+data: parsed,
+html: parsed === null ? text : null,
+headers: response.headers,
+};
+}
+const elementAtomSubs = new WeakMap();
+function bindElementToScope(el, scope) {
+const atom = resolveAtomByScope(scope, true);
+if (!atom) return;
+const apply = function (value) {
+if (value === undefined || value === null) return;
+try { patch(value, el); }
+catch (e) { warn("s-bind apply failed: " + e.message); }
+};
+apply(atom.get());
+const unsub = atom.subscribe(apply);
+let set = elementAtomSubs.get(el);
+if (!set) { set = new Set(); elementAtomSubs.set(el, set); }
+set.add(unsub);
+}
+function unbindElementAtoms(el) {
+const set = elementAtomSubs.get(el);
+if (!set) return;
+for (const unsub of set) {
+try { unsub(); } catch (e) {}
+}
+elementAtomSubs.delete(el);
+}
+function initScopeBindings() {
+document.querySelectorAll("[s-bind]").forEach(function (el) {
+const scope = el.getAttribute("s-bind");
+if (scope) bindElementToScope(el, scope);
+});
+}
+function seedAtomsFromSSR() {
+if (typeof window === "undefined") return;
+const seeds = window.__silcrow_seed || window.__pilcrow_props;
+if (!seeds || typeof seeds !== "object") return;
+for (const key in seeds) {
+if (!Object.prototype.hasOwnProperty.call(seeds, key)) continue;
+if (BLOCKED_ATOM_KEYS.has(key)) continue;
+const value = seeds[key];
+let pathKey = key;
+try { pathKey = new URL(key, location.origin).pathname; } catch (e) {}
+getOrCreateAtom(routeAtoms, pathKey, undefined).set(value);
+prefetchPromises.set(pathKey, Promise.resolve(value));
+}
+}
+const instanceCache = new WeakMap();
+const validatedTemplates = new WeakSet();
+const localBindingsCache = new WeakMap();
+const identityMap = new WeakMap();
+const patchMiddleware = [];
+const PATH_RE = /^\.?[A-Za-z0-9_-]+(\.[A-Za-z0-9_-]+)*$/;
+function isValidPath(p) { return PATH_RE.test(p); }
+const knownProps = {
+value: "string",
+checked: "boolean",
+disabled: "boolean",
+selected: "boolean",
+hidden: "boolean",
+required: "boolean",
+readOnly: "boolean",
+src: "string",
+href: "string",
+selectedIndex: "number",
+};
+const URL_BINDING_PROPS = new Set([
+"href", "src", "action", "formaction", "xlink:href",
+"poster", "cite", "background"
+]);
+const BLOCKED_KEYS = new Set(["__proto__", "constructor", "prototype"]);
+function resolvePath(obj, path) {
+if (typeof obj !== "object" || obj === null) return undefined;
+if (!isValidPath(path)) return undefined;
+const parts = path.split(".");
+let cur = obj;
+for (const part of parts) {
+if (BLOCKED_KEYS.has(part)) return undefined;
+if (!Object.prototype.hasOwnProperty.call(cur, part)) return undefined;
+cur = cur[part];
+if (cur === null || cur === undefined) {
+return parts.indexOf(part) === parts.length - 1 ? cur : undefined;
+}
+}
+return cur;
+}
+function resolveRoot(root) {
+if (typeof root === "string") return document.querySelector(root) || document.body;
+return root || document.body;
+}
+function getStableId(obj) {
+if (obj === null || typeof obj !== 'object') return String(obj);
+let id = identityMap.get(obj);
+if (!id) {
+id = crypto.randomUUID();
+identityMap.set(obj, id);
+}
+return id;
+}
+function safeClone(obj) {
+try { return structuredClone(obj); }
+catch { return JSON.parse(JSON.stringify(obj)); }
+}
+function parseForExpression(expr) {
+const match = expr.match(/^\s*([A-Za-z0-9_-]+)\s+in\s+([A-Za-z0-9_-]+(?:\.[A-Za-z0-9_-]+)*)\s*$/);
+return match ? {alias: match[1], path: match[2]} : null;
+}
+function setValue(el, prop, value) {
+if (isOnHandler(prop)) {
+throwErr("Binding to event handler attribute rejected: " + prop);
+return;
+}
+if (prop === null) {
+if (value && typeof value === "object" && !Array.isArray(value)) {
+for (const key in value) {
+setValue(el, key, value[key]);
+}
+return;
+}
+el.textContent = value == null ? "" : String(value);
+return;
+}
+if (prop === "text") {
+el.textContent = value == null ? "" : String(value);
+return;
+}
+if (prop === "show") {
+el.style.display = value ? "" : "none";
+return;
+}
+if (prop === "class") {
+if (value && typeof value === "object" && !Array.isArray(value)) {
+for (const [className, enabled] of Object.entries(value)) {
+el.classList.toggle(className, !!enabled);
+}
+} else {
+el.setAttribute("class", value == null ? "" : String(value));
+}
+return;
+}
+if (prop === "style") {
+if (value && typeof value === "object" && !Array.isArray(value)) {
+for (const [rule, val] of Object.entries(value)) {
+el.style[rule] = val == null ? "" : String(val);
+}
+} else {
+el.setAttribute("style", value == null ? "" : String(value));
+}
+return;
+}
+const name = String(prop).toLowerCase();
+if (URL_BINDING_PROPS.has(name)) {
+const allowDataImage = name === "src" && el.tagName === "IMG";
+if (!hasSafeProtocol(value, allowDataImage)) {
+warn("Rejected unsafe URL in binding: " + prop);
+value = "";
+}
+}
+if (value == null) {
+if (prop in knownProps) {
+const t = knownProps[prop];
+if (t === "boolean") el[prop] = false;
+else if (t === "number") el[prop] = 0;
+else el[prop] = "";
+} else {
+el.removeAttribute(prop);
+}
+return;
+}
+if (prop in knownProps) {
+el[prop] = value;
+} else if (value === false) {
+el.removeAttribute(prop);
+} else if (value === true) {
+el.setAttribute(prop, "");
+} else {
+el.setAttribute(prop, String(value));
+}
+}
+function parseBind(el) {
+const spreadPath = el.getAttribute("s-use");
+if (spreadPath) return { path: spreadPath, prop: null };
+for (const attr of el.attributes) {
+if (attr.name.startsWith(":") && attr.name !== ":key") {
+const prop = attr.name.slice(1);
+if (prop.startsWith("on") || prop === "style" || prop === "srcdoc") {
+warn('Blocked dangerous binding: :' + prop);
+continue;
+}
+return { path: attr.value, prop };
+}
+}
+return null;
+}
+function scanBindings(root, alias = null) {
+const bindings = new Map();
+const selector = '[s-use], [\\:text], [\\:class], [\\:style], [\\:show], [\\:value], [\\:disabled], [\\:hidden]';
+const elements = [];
+if (root.matches && root.matches(selector)) elements.push(root);
+elements.push(...root.querySelectorAll(selector));
+for (const el of elements) {
+if (el.closest("template")) continue;
+const parsed = parseBind(el);
+if (!parsed) continue;
+const { path, prop } = parsed;
+if (alias && path.startsWith(alias + ".")) {
+const field = path.substring(alias.length + 1);
+if (!bindings.has(field)) bindings.set(field, []);
+bindings.get(field).push({ el, prop });
+} else if (!alias) {
+if (!bindings.has(path)) bindings.set(path, []);
+bindings.get(path).push({ el, prop });
+}
+}
+return bindings;
+}
+function reconcile(container, template, items, alias, keyPath) {
+const existingBlocks = new Map();
+for (const child of container.children) {
+const k = child.getAttribute(":key");
+if (k) {
+if (!existingBlocks.has(k)) existingBlocks.set(k, []);
+existingBlocks.get(k).push(child);
+}
+}
+const nextKeys = new Set();
+let anchor = template;
+for (const item of items) {
+const key = String(keyPath ? resolvePath(item, keyPath) : getStableId(item));
+if (nextKeys.has(key)) {
+warn('Duplicate :key "' + key + '" in s-for — item skipped');
+continue;
+}
+nextKeys.add(key);
+let block = existingBlocks.get(key);
+if (!block) {
+const frag = template.content.cloneNode(true);
+block = Array.from(frag.children).filter(n => n.nodeType === 1);
+block.forEach(el => el.setAttribute(":key", key));
+}
+block.forEach(node => {
+patchItem(node, item, alias);
+if (anchor.nextElementSibling !== node) anchor.after(node);
+anchor = node;
+});
+}
+for (const [key, nodes] of existingBlocks) {
+if (!nextKeys.has(key)) nodes.forEach(n => n.remove());
+}
+}
+function patchItem(node, item, alias) {
+let bindings = localBindingsCache.get(node);
+if (!bindings) {
+bindings = scanBindings(node, alias);
+localBindingsCache.set(node, bindings);
+}
+for (const field in item) {
+const targets = bindings.get(field);
+if (targets) targets.forEach(t => setValue(t.el, t.prop, item[field]));
+}
+}
+function mergeOrRemoveItem(container, template, item, alias, keyPath) {
+const key = String(resolvePath(item, keyPath));
+if (!key) return;
+if (item._remove) {
+for (const child of [...container.children]) {
+if (child.getAttribute(":key") === key) child.remove();
+}
+return;
+}
+const existing = [];
+for (const child of container.children) {
+if (child.getAttribute(":key") === key) existing.push(child);
+}
+if (existing.length > 0) {
+existing.forEach(node => patchItem(node, item, alias));
+} else {
+const frag = template.content.cloneNode(true);
+const block = Array.from(frag.children).filter(n => n.nodeType === 1);
+block.forEach(el => {
+el.setAttribute(":key", key);
+patchItem(el, item, alias);
+container.appendChild(el);
+});
+}
+}
+function buildMaps(root) {
+const collections = [];
+root.querySelectorAll("template[s-for]").forEach(tpl => {
+const expr = parseForExpression(tpl.getAttribute("s-for"));
+const keyAttr = tpl.getAttribute(":key");
+const keyPath = keyAttr?.startsWith(expr.alias + ".")
+? keyAttr.substring(expr.alias.length + 1)
+: keyAttr;
+collections.push({path: expr.path, tpl, alias: expr.alias, keyPath});
+});
+return {scalars: scanBindings(root), collections};
+}
+function patch(data, root, options = {}) {
+const element = resolveRoot(root);
+let transformedData = data;
+try {
+transformedData = patchMiddleware.reduce((acc, fn) => fn(safeClone(acc)) ?? acc, safeClone(data));
+} catch (err) {
+transformedData = data;
+}
+if (transformedData?._toasts) processToasts(true, transformedData);
+if (
+transformedData?.data !== undefined &&
+Object.keys(transformedData).length === 1 &&
+typeof transformedData.data === "object" &&
+transformedData.data !== null &&
+!Array.isArray(transformedData.data)
+) {
+transformedData = transformedData.data;
+}
+let instance = instanceCache.get(element);
+if (!instance || options.invalidate) {
+instance = buildMaps(element);
+instanceCache.set(element, instance);
+}
+for (const [path, bindings] of instance.scalars.entries()) {
+const val = resolvePath(transformedData, path);
+if (val !== undefined) bindings.forEach(b => setValue(b.el, b.prop, val));
+}
+instance.collections.forEach(col => {
+const val = resolvePath(transformedData, col.path);
+if (Array.isArray(val)) {
+reconcile(col.tpl.parentElement, col.tpl, val, col.alias, col.keyPath);
+} else if (val && typeof val === "object" && col.keyPath) {
+mergeOrRemoveItem(col.tpl.parentElement, col.tpl, val, col.alias, col.keyPath);
+}
+});
+element.dispatchEvent(new CustomEvent("silcrow:patched", {
+bubbles: true,
+detail: {paths: Array.from(instance.scalars.keys()), target: element},
+}));
+}
+function invalidate(root) {
+const element = resolveRoot(root);
+instanceCache.delete(element);
+element.querySelectorAll('[\\:key]').forEach(el => localBindingsCache.delete(el));
+}
+function stream(root) {
+let pending = null;
+return function(data) {
+pending = data;
+queueMicrotask(() => {
+if (pending === data) {
+patch(pending, root);
+pending = null;
+}
+});
+};
+}
+const liveConnections = new Map();
+const liveConnectionsByUrl = new Map();
+const sseHubs = new Map();
+const MAX_BACKOFF = 30000;
+const LIVE_HTTP_PROTOCOLS = new Set(["http:", "https:"]);
+function isLikelyLiveUrl(value) {
+return (
+typeof value === "string" &&
+(value.startsWith("/") ||
+value.startsWith("http://") ||
+value.startsWith("https://"))
+);
+}
+function normalizeSSEEndpoint(rawUrl) {
+if (typeof rawUrl !== "string") return null;
+const value = rawUrl.trim();
+if (!value) return null;
+let parsed;
+try {
+parsed = new URL(value, location.origin);
+} catch (e) {
+warn("Invalid SSE URL: " + value);
+return null;
+}
+if (!LIVE_HTTP_PROTOCOLS.has(parsed.protocol)) {
+warn("Rejected non-http(s) SSE URL: " + parsed.href);
+return null;
+}
+if (parsed.origin !== location.origin) {
+warn("Rejected cross-origin SSE URL: " + parsed.href);
+return null;
+}
+return parsed.href;
+}
+function resolveLiveTarget(selector, fallback) {
+if (typeof selector !== "string" || !selector) return fallback;
+return document.querySelector(selector) || null;
+}
+function applyLivePatchPayload(payload, fallbackTarget) {
+if (
+payload &&
+typeof payload === "object" &&
+!Array.isArray(payload) &&
+Object.prototype.hasOwnProperty.call(payload, "target")
+) {
+if (!Object.prototype.hasOwnProperty.call(payload, "data")) {
+warn("SSE patch envelope missing data field");
+return;
+}
+const target = resolveLiveTarget(payload.target, fallbackTarget);
+if (target) {
+patch(payload.data, target);
+}
+return;
+}
+patch(payload, fallbackTarget);
+}
+function registerLiveState(state) {
+liveConnections.set(state.element, state);
+let byUrl = liveConnectionsByUrl.get(state.url);
+if (!byUrl) {
+byUrl = new Set();
+liveConnectionsByUrl.set(state.url, byUrl);
+}
+byUrl.add(state);
+}
+function unregisterLiveState(state) {
+if (liveConnections.get(state.element) === state) liveConnections.delete(state.element);
+const byUrl = liveConnectionsByUrl.get(state.url);
+if (byUrl) {
+byUrl.delete(state);
+if (byUrl.size === 0) liveConnectionsByUrl.delete(state.url);
+}
+}
+function pauseLiveState(state) {
+state.paused = true;
+if (state.protocol !== "ws" && state.hub) {
+state.paused = true;
+state.hub.paused = true;
+if (state.hub.reconnectTimer) {
+clearTimeout(state.hub.reconnectTimer);
+state.hub.reconnectTimer = null;
+}
+if (state.hub.es) {
+state.hub.es.close();
+state.hub.es = null;
+}
+}
+}
+function resolveLiveStates(root) {
+if (typeof root === "string") {
+if (
+root.startsWith("/") ||
+root.startsWith("http://") ||
+root.startsWith("https://")
+) {
+const fullUrl = new URL(root, location.origin).href;
+let states = liveConnectionsByUrl.get(fullUrl);
+if (!states || states.size === 0) {
+const wsUrl = fullUrl.replace(/^http(s?)/, "ws$1");
+states = liveConnectionsByUrl.get(wsUrl);
+}
+return states ? Array.from(states) : [];
+}
+const element = document.querySelector(root);
+if (!element) return [];
+const state = liveConnections.get(element);
+return state ? [state] : [];
+}
+if (!root) return [];
+const state = liveConnections.get(root);
+return state ? [state] : [];
+}
+function onSSEEvent(e) {
+const path = e?.detail?.path;
+if (!path || typeof path !== "string") return;
+const root = e?.detail?.target || document.body;
+openLive(root, path);
+}
+function createSseHub(url) {
+return {
+url,
+es: null,
+subscribers: new Set(),
+backoff: 1000,
+paused: false,
+reconnectTimer: null,
+};
+}
+function getOrCreateSseHub(url) {
+let hub = sseHubs.get(url);
+if (!hub) {
+hub = createSseHub(url);
+sseHubs.set(url, hub);
+}
+return hub;
+}
+function removeSseHub(hub) {
+if (hub.subscribers.size > 0) return;
+if (hub.reconnectTimer) {
+clearTimeout(hub.reconnectTimer);
+hub.reconnectTimer = null;
+}
+if (hub.es) {
+hub.es.close();
+hub.es = null;
+}
+sseHubs.delete(hub.url);
+}
+function openLive(root, url) {
+const element = typeof root === "string" ? document.querySelector(root) : root;
+if (!element) {
+warn("Live root not found: " + root);
+return;
+}
+const fullUrl = normalizeSSEEndpoint(url);
+if (!fullUrl) return;
+const existing = liveConnections.get(element);
+if (existing && existing.protocol !== "ws") {
+unsubscribeSse(element);
+}
+const hub = getOrCreateSseHub(fullUrl);
+hub.subscribers.add(element);
+const state = {
+url: fullUrl,
+element,
+paused: false,
+protocol: "sse",
+hub,
+};
+liveConnections.set(element, state);
+let byUrl = liveConnectionsByUrl.get(fullUrl);
+if (!byUrl) {
+byUrl = new Set();
+liveConnectionsByUrl.set(fullUrl, byUrl);
+}
+byUrl.add(state);
+connectSseHub(hub);
+}
+function unsubscribeSse(element) {
+const state = liveConnections.get(element);
+if (!state || state.protocol === "ws") return;
+const hub = state.hub;
+if (hub) {
+hub.subscribers.delete(element);
+if (hub.subscribers.size === 0) removeSseHub(hub);
+}
+if (liveConnections.get(element) === state) liveConnections.delete(element);
+const byUrl = liveConnectionsByUrl.get(state.url);
+if (byUrl) {
+byUrl.delete(state);
+if (byUrl.size === 0) liveConnectionsByUrl.delete(state.url);
+}
+}
+function connectSseHub(hub) {
+if (hub.paused || hub.subscribers.size === 0) return;
+if (hub.es && hub.es.readyState < EventSource.CLOSED) return;
+const es = new EventSource(hub.url);
+hub.es = es;
+es.onopen = function () {
+hub.backoff = 1000;
+hub.subscribers.forEach(function (el) {
+document.dispatchEvent(new CustomEvent("silcrow:live:connect", {
+bubbles: true,
+detail: {root: el, url: hub.url, protocol: "sse"},
+}));
+});
+};
+es.onmessage = function (e) {
+try {
+const payload = JSON.parse(e.data);
+const fallback = hub.subscribers.size > 0
+? hub.subscribers.values().next().value
+: document.body;
+applyLivePatchPayload(payload, fallback);
+const atomData =
+payload && typeof payload === "object" && !Array.isArray(payload) &&
+Object.prototype.hasOwnProperty.call(payload, "target") &&
+Object.prototype.hasOwnProperty.call(payload, "data")
+? payload.data
+: payload;
+getOrCreateAtom(streamAtoms, hub.url, undefined).patch(atomData);
+} catch (err) {
+warn("Failed to parse SSE message: " + err.message);
+}
+};
+es.addEventListener("patch", function (e) {
+try {
+const payload = JSON.parse(e.data);
+let target = null;
+let data = payload;
+if (payload && typeof payload === "object" && !Array.isArray(payload) &&
+Object.prototype.hasOwnProperty.call(payload, "target")) {
+data = payload.data;
+if (payload.target) target = document.querySelector(payload.target);
+}
+if (!target && hub.subscribers.size > 0) {
+target = hub.subscribers.values().next().value;
+}
+if (target && data !== undefined) patch(data, target);
+if (data !== undefined) {
+getOrCreateAtom(streamAtoms, hub.url, undefined).patch(data);
+}
+} catch (err) {
+warn("Failed to parse SSE patch event: " + err.message);
+}
+});
+es.addEventListener("html", function (e) {
+try {
+const payload = JSON.parse(e.data);
+const target = payload.target
+? document.querySelector(payload.target)
+: (hub.subscribers.size > 0 ? hub.subscribers.values().next().value : null);
+if (target && Object.prototype.hasOwnProperty.call(payload, "html")) {
+safeSetHTML(target, payload.html == null ? "" : String(payload.html));
+}
+} catch (err) {
+warn("Failed to parse SSE html event: " + err.message);
+}
+});
+es.addEventListener("invalidate", function (e) {
+const selector = e.data ? e.data.trim() : null;
+if (selector) {
+const target = document.querySelector(selector);
+if (target) invalidate(target);
+} else {
+hub.subscribers.forEach(function (el) {invalidate(el);});
+}
+});
+es.addEventListener("navigate", function (e) {
+if (e.data) navigateSameOrigin(e.data.trim(), {trigger: "sse"});
+});
+es.addEventListener("custom", function (e) {
+try {
+const payload = JSON.parse(e.data);
+document.dispatchEvent(new CustomEvent("silcrow:sse:" + (payload.event || "custom"), {
+bubbles: true,
+detail: {url: hub.url, data: payload.data},
+}));
+} catch (err) {
+warn("Failed to parse SSE custom event: " + err.message);
+}
+});
+es.onerror = function () {
+es.close();
+hub.es = null;
+if (hub.paused || hub.subscribers.size === 0) {
+if (hub.subscribers.size === 0) removeSseHub(hub);
+return;
+}
+const reconnectIn = hub.backoff;
+hub.subscribers.forEach(function (el) {
+document.dispatchEvent(new CustomEvent("silcrow:live:disconnect", {
+bubbles: true,
+detail: {root: el, url: hub.url, protocol: "sse", reconnectIn},
+}));
+});
+hub.reconnectTimer = setTimeout(function () {
+hub.reconnectTimer = null;
+connectSseHub(hub);
+}, reconnectIn);
+hub.backoff = Math.min(hub.backoff * 2, MAX_BACKOFF);
+};
+}
+function disconnectLive(root) {
+const states = resolveLiveStates(root);
+if (!states.length) return;
+for (const state of states) {
+pauseLiveState(state);
+}
+}
+function reconnectLive(root) {
+const states = resolveLiveStates(root);
+if (!states.length) return;
+const reconnectedHubs = new Set();
+for (const state of states) {
+state.paused = false;
+if (state.protocol === "ws") {
+const hub = getOrCreateWsHub(state.url);
+hub.subscribers.add(state.element);
+state.hub = hub;
+if (!reconnectedHubs.has(hub)) {
+reconnectedHubs.add(hub);
+hub.paused = false;
+hub.backoff = 1000;
+if (hub.reconnectTimer) {
+clearTimeout(hub.reconnectTimer);
+hub.reconnectTimer = null;
+}
+connectWsHub(hub);
+}
+} else {
+const hub = state.hub;
+if (!hub) continue;
+state.paused = false;
+hub.paused = false;
+hub.backoff = 1000;
+if (hub.reconnectTimer) {
+clearTimeout(hub.reconnectTimer);
+hub.reconnectTimer = null;
+}
+connectSseHub(hub);
+}
+}
+}
+function destroyAllLive() {
+for (const state of liveConnections.values()) {
+if (state.protocol !== "ws") state.paused = true;
+}
+liveConnections.clear();
+liveConnectionsByUrl.clear();
+for (const hub of sseHubs.values()) {
+if (hub.reconnectTimer) clearTimeout(hub.reconnectTimer);
+if (hub.es) hub.es.close();
+}
+sseHubs.clear();
+for (const hub of wsHubs.values()) {
+if (hub.reconnectTimer) clearTimeout(hub.reconnectTimer);
+if (hub.socket) hub.socket.close();
+}
+wsHubs.clear();
+}
+function initLiveElements() {
+document.querySelectorAll("[s-sse]").forEach(el => {
+const url = el.getAttribute("s-sse");
+if (url) openLive(el, url);
+});
+document.querySelectorAll("[s-ws], [s-wss]").forEach(el => {
+const url = el.getAttribute("s-ws") || el.getAttribute("s-wss");
+if (url) openWsLive(el, url);
+});
+}
+function normalizeWsEndpoint(rawUrl) {
+if (typeof rawUrl !== "string") return null;
+const value = rawUrl.trim();
+if (!value) return null;
+let parsed;
+try {
+parsed = new URL(value, location.origin);
+} catch (e) {
+warn("Invalid WS URL: " + value);
+return null;
+}
+if (parsed.protocol === "https:") {
+parsed.protocol = "wss:";
+} else if (parsed.protocol === "http:") {
+parsed.protocol = "ws:";
+}
+if (parsed.protocol !== "ws:" && parsed.protocol !== "wss:") {
+warn("Rejected non-ws(s) WebSocket URL: " + parsed.href);
+return null;
+}
+const expectedOrigin = location.origin.replace(/^http(s?)/, "ws$1");
+if (parsed.origin !== expectedOrigin) {
+warn("Rejected cross-origin WebSocket URL: " + parsed.href);
+return null;
+}
+return parsed.href;
+}
+const wsHubs = new Map();
+function createWsHub(url) {
+return {
+url,
+socket: null,
+subscribers: new Set(),
+backoff: 1000,
+paused: false,
+reconnectTimer: null,
+};
+}
+function getOrCreateWsHub(url) {
+let hub = wsHubs.get(url);
+if (!hub) {
+hub = createWsHub(url);
+wsHubs.set(url, hub);
+}
+return hub;
+}
+function removeWsHub(hub) {
+if (hub.subscribers.size > 0) return;
+if (hub.reconnectTimer) {
+clearTimeout(hub.reconnectTimer);
+hub.reconnectTimer = null;
+}
+if (hub.socket) {
+hub.socket.close();
+hub.socket = null;
+}
+wsHubs.delete(hub.url);
+}
+function connectWsHub(hub) {
+if (hub.paused) return;
+if (hub.socket && hub.socket.readyState <= WebSocket.OPEN) return;
+const socket = new WebSocket(hub.url);
+hub.socket = socket;
+socket.onopen = function () {
+hub.backoff = 1000;
+document.dispatchEvent(
+new CustomEvent("silcrow:live:connect", {
+bubbles: true,
+detail: {
+url: hub.url,
+protocol: "ws",
+subscribers: Array.from(hub.subscribers),
+},
+})
+);
+};
+socket.onmessage = function (e) {
+dispatchWsMessage(hub, e.data);
+};
+socket.onclose = function () {
+hub.socket = null;
+if (hub.paused) return;
+if (hub.subscribers.size === 0) {
+removeWsHub(hub);
+return;
+}
+const reconnectIn = hub.backoff;
+document.dispatchEvent(
+new CustomEvent("silcrow:live:disconnect", {
+bubbles: true,
+detail: {
+url: hub.url,
+protocol: "ws",
+reconnectIn,
+subscribers: Array.from(hub.subscribers),
+},
+})
+);
+hub.reconnectTimer = setTimeout(function () {
+hub.reconnectTimer = null;
+connectWsHub(hub);
+}, reconnectIn);
+hub.backoff = Math.min(hub.backoff * 2, MAX_BACKOFF);
+};
+socket.onerror = function () {
+};
+}
+function dispatchWsMessage(hub, rawData) {
+try {
+const msg = JSON.parse(rawData);
+const type = msg && msg.type;
+let targets;
+if (msg.target) {
+const el = document.querySelector(msg.target);
+targets = el ? [el] : [];
+} else {
+targets = hub.subscribers;
+}
+if (type === "patch") {
+if (msg.data !== undefined) {
+for (const el of targets) {
+patch(msg.data, el);
+}
+getOrCreateAtom(streamAtoms, hub.url, undefined).patch(msg.data);
+}
+} else if (type === "html") {
+for (const el of targets) {
+safeSetHTML(el, msg.markup == null ? "" : String(msg.markup));
+}
+} else if (type === "invalidate") {
+for (const el of targets) {
+invalidate(el);
+}
+} else if (type === "navigate") {
+if (msg.path) {
+navigateSameOrigin(msg.path.trim(), {trigger: "ws"});
+}
+} else if (type === "custom") {
+document.dispatchEvent(
+new CustomEvent("silcrow:ws:" + (msg.event || "message"), {
+bubbles: true,
+detail: {url: hub.url, data: msg.data},
+})
+);
+} else {
+warn("Unknown WS event type: " + type);
+}
+} catch (err) {
+warn("Failed to parse WS message: " + err.message);
+}
+}
+function unsubscribeWs(element) {
+const state = liveConnections.get(element);
+if (!state || state.protocol !== "ws") return;
+const hub = state.hub;
+if (hub) {
+hub.subscribers.delete(element);
+if (hub.subscribers.size === 0) {
+removeWsHub(hub);
+}
+}
+unregisterLiveState(state);
+}
+function openWsLive(root, url) {
+const element = typeof root === "string" ? document.querySelector(root) : root;
+if (!element) {
+warn("WS live root not found: " + root);
+return;
+}
+const fullUrl = normalizeWsEndpoint(url);
+if (!fullUrl) return;
+const existing = liveConnections.get(element);
+if (existing && existing.protocol === "ws") {
+unsubscribeWs(element);
+} else if (existing) {
+pauseLiveState(existing);
+unregisterLiveState(existing);
+}
+const hub = getOrCreateWsHub(fullUrl);
+hub.subscribers.add(element);
+const state = {
+es: null,
+socket: null,
+url: fullUrl,
+element,
+backoff: 0,
+paused: false,
+reconnectTimer: null,
+protocol: "ws",
+hub,
+};
+registerLiveState(state);
+connectWsHub(hub);
+}
+function sendWs(data, root) {
+const states = resolveLiveStates(root);
+if (!states.length) {
+warn("No live connection found for send target");
+return;
+}
+const sentHubs = new Set();
+for (const state of states) {
+if (state.protocol !== "ws") {
+warn("Cannot send on SSE connection — use WS for bidirectional");
+continue;
+}
+const hub = state.hub;
+if (!hub || sentHubs.has(hub)) continue;
+sentHubs.add(hub);
+if (!hub.socket || hub.socket.readyState !== WebSocket.OPEN) {
+warn("WebSocket not open for send");
+continue;
+}
+try {
+const payload = typeof data === "string" ? data : JSON.stringify(data);
+hub.socket.send(payload);
+} catch (err) {
+warn("WS send failed: " + err.message);
+}
+}
+}
+const VERB_ATTRS = ["s-get", "s-post", "s-put", "s-delete", "s-patch"];
+const VERB_SELECTOR = VERB_ATTRS.map(function(a) { return "[" + a + "]"; }).join(",");
+const FORM_VERB_SELECTOR = VERB_ATTRS.map(function(a) { return "form[" + a + "]"; }).join(",");
+const DEFAULT_TIMEOUT = 30000;
+const CACHE_TTL = 5 * 60 * 1000;
+const MAX_CACHE = 50;
+const abortMap = new WeakMap();
+let routeHandler = null;
+let errorHandler = null;
+const responseCache = new Map();
+let historyIdx = 0;
+let historyTransition = null;
+let transitionSeq = 0;
+const preloadInflight = new Map();
+function resolveVerb(el) {
+for (var i = 0; i < VERB_ATTRS.length; i++) {
+var raw = el.getAttribute(VERB_ATTRS[i]);
+if (raw !== null) {
+if (raw.includes(":key")) {
+var closest = el.closest("[:key]");
+if (closest) {
+var id = closest.getAttribute(":key");
+raw = raw.replace(/:key/g, id);
+}
+}
+try {
+return {
+url: new URL(raw, location.origin).href,
+method: VERB_ATTRS[i].slice(2).toUpperCase()
+};
+} catch (e) {
+return null;
+}
+}
+}
+return null;
+}
+function getTarget(el) {
+let sel = el.getAttribute("s-target");
+if (sel) {
+if (sel.includes(":key")) {
+const closest = el.closest("[:key]");
+if (closest) sel = sel.replace(/:key/g, closest.getAttribute(":key"));
+}
+const target = document.querySelector(sel);
+if (target) return target;
+}
+const listItem = el.closest("[:key]");
+if (listItem) {
+const container = listItem.parentElement;
+if (container && container.querySelector("template[s-for]")) {
+return container;
+}
+return listItem;
+}
+return el;
+}
+function isSafeBoostHref(anchor) {
+const href = anchor.getAttribute("href");
+if (!href || href.startsWith("#")) return false;
+if (anchor.hasAttribute("download")) return false;
+if (anchor.getAttribute("target") === "_blank") return false;
+try {
+const url = new URL(href, location.origin);
+return url.origin === location.origin;
+} catch (e) {
+return false;
+}
+}
+function getBoostTarget(boostEl) {
+const sel = boostEl.getAttribute("s-target");
+if (sel) {
+const t = document.querySelector(sel);
+if (t) return t;
+}
+return document.body;
+}
+function getTimeout(el) {
+const val = el?.getAttribute("s-timeout");
+return val ? parseInt(val, 10) : DEFAULT_TIMEOUT;
+}
+function showLoading(el) {
+el.classList.add("silcrow-loading");
+el.setAttribute("aria-busy", "true");
+}
+function hideLoading(el) {
+el.classList.remove("silcrow-loading");
+el.removeAttribute("aria-busy");
+}
+function cacheKey(url, wantsHTML) {
+return (wantsHTML ? "html " : "json ") + url;
+}
+function cacheSet(key, entry) {
+responseCache.set(key, entry);
+if (responseCache.size > MAX_CACHE) {
+const oldest = responseCache.keys().next().value;
+responseCache.delete(oldest);
+}
+}
+function cacheGet(key) {
+const cached = responseCache.get(key);
+if (!cached) return null;
+if (Date.now() - cached.ts > CACHE_TTL) {
+responseCache.delete(key);
+return null;
+}
+return cached;
+}
+function bustCacheOnMutation() {
+responseCache.clear();
+prefetchPromises.clear();
+}
+function processSideEffectHeaders(sideEffects, primaryTarget) {
+if (!sideEffects) return;
+if (sideEffects.patch) {
+try {
+const payload = JSON.parse(sideEffects.patch);
+if (
+payload &&
+typeof payload === "object" &&
+payload.target &&
+Object.prototype.hasOwnProperty.call(payload, "data")
+) {
+const el = document.querySelector(payload.target);
+if (el) patch(payload.data, el);
+}
+} catch (e) {
+warn("Failed to process silcrow-patch header: " + e.message);
+}
+}
+if (sideEffects.invalidate) {
+const el = document.querySelector(sideEffects.invalidate);
+if (el) invalidate(el);
+}
+if (sideEffects.navigate) {
+navigateSameOrigin(sideEffects.navigate, {trigger: "header"});
+}
+if (sideEffects.sse) {
+const ssePath = normalizeSSEEndpoint(sideEffects.sse);
+if (!ssePath) return;
+document.dispatchEvent(
+new CustomEvent("silcrow:sse", {
+bubbles: true,
+detail: {path: ssePath, target: primaryTarget || null},
+})
+);
+}
+if (sideEffects.ws) {
+const target = primaryTarget || document.body;
+openWsLive(target, sideEffects.ws);
+}
+}
+function buildFetchOptions(method, body, wantsHTML, signal) {
+const opts = {
+method,
+headers: {
+"silcrow-target": "true",
+"Accept": wantsHTML ? "text/html" : "application/json",
+},
+signal,
+};
+if (body) {
+if (body instanceof FormData) {
+opts.body = body;
+} else if (body instanceof URLSearchParams) {
+opts.headers["Content-Type"] = "application/x-www-form-urlencoded";
+opts.body = body;
+} else {
+opts.headers["Content-Type"] = "application/json";
+opts.body = JSON.stringify(body);
+}
+}
+return opts;
+}
+function processResponseHeaders(response, fullUrl) {
+const result = {
+redirected: response.redirected,
+finalUrl: response.url || fullUrl,
+pushUrl: null,
+retargetSelector: null,
+transition: response.headers.get("silcrow-transition"),
+scroll: response.headers.get("silcrow-scroll"),
+sideEffects: {
+patch: response.headers.get("silcrow-patch"),
+invalidate: response.headers.get("silcrow-invalidate"),
+navigate: response.headers.get("silcrow-navigate"),
+sse: response.headers.get("silcrow-sse"),
+ws: response.headers.get("silcrow-ws"),
+},
+};
+const triggerHeader = response.headers.get("silcrow-trigger");
+if (triggerHeader) {
+try {
+const triggers = JSON.parse(triggerHeader);
+Object.entries(triggers).forEach(([evt, detail]) => {
+document.dispatchEvent(new CustomEvent(evt, {bubbles: true, detail}));
+});
+} catch (e) {
+document.dispatchEvent(new CustomEvent(triggerHeader, {bubbles: true}));
+}
+}
+result.retargetSelector = response.headers.get("silcrow-retarget");
+result.pushUrl = response.headers.get("silcrow-push");
+if (result.pushUrl && !isSameOriginUrl(result.pushUrl)) {
+warn("Rejected cross-origin silcrow-push URL: " + result.pushUrl);
+result.pushUrl = null;
+}
+if (result.pushUrl) {
+result.finalUrl = new URL(result.pushUrl, location.origin).href;
+result.redirected = true;
+}
+return result;
+}
+function prepareSwapContent(text, contentType, targetSelector) {
+const isJSON = contentType.includes("application/json");
+let swapContent;
+if (isJSON) {
+swapContent = JSON.parse(text);
+processToasts(true, swapContent);
+} else {
+const isFullPage = !targetSelector;
+swapContent = extractHTML(text, targetSelector, isFullPage);
+processToasts(false);
+}
+return {swapContent, isJSON};
+}
+async function swapWithTransition(proceed, name, direction) {
+if (!name || typeof document.startViewTransition !== "function") {
+proceed();
+return;
+}
+const root = document.documentElement;
+const token = ++transitionSeq;
+root.setAttribute("data-silcrow-transition", name);
+root.setAttribute("data-silcrow-transition-direction", direction);
+const clear = () => {
+if (transitionSeq === token) {
+root.removeAttribute("data-silcrow-transition");
+root.removeAttribute("data-silcrow-transition-direction");
+}
+};
+const vt = document.startViewTransition(proceed);
+vt.ready.catch(() => {});
+vt.finished.then(clear, clear);
+await vt.updateCallbackDone;
+}
+function finalizeNavigation(ctx) {
+const {pushUrl, redirected, finalUrl, fullUrl, shouldPushHistory,
+trigger, targetSelector, targetEl, sideEffects, transition, scroll, isJSON,
+wantsHTML} = ctx;
+processSideEffectHeaders(sideEffects, targetEl);
+const finalHistoryUrl = pushUrl || (redirected ? finalUrl : fullUrl);
+if (shouldPushHistory && trigger !== "popstate") {
+history.pushState(
+{silcrow: true, url: finalHistoryUrl, targetSelector, transition, wantsHTML, idx: historyIdx + 1},
+"",
+finalHistoryUrl
+);
+historyIdx += 1;
+historyTransition = transition;
+}
+if (trigger === "popstate") {
+const saved = (history.state || {}).scrollY;
+window.scrollTo(0, saved || 0);
+} else if (scroll === "top" || (shouldPushHistory && scroll !== "preserve")) {
+window.scrollTo(0, 0);
+}
+document.dispatchEvent(
+new CustomEvent("silcrow:load", {
+bubbles: true,
+detail: {url: finalUrl, target: targetEl, redirected},
+})
+);
+if (targetEl) {
+targetEl.querySelectorAll("[s-sse]").forEach(function (el) {
+const url = el.getAttribute("s-sse");
+if (url) openLive(el, url);
+});
+if (!isJSON) initDrafts(targetEl);
+}
+}
+async function navigate(url, options = {}) {
+const {
+method = "GET",
+body = null,
+target = null,
+trigger = "click",
+skipHistory = false,
+sourceEl = null,
+direction = "forward",
+wantsHTML: wantsHTMLOption = null,
+} = options;
+let transition = options.transition ||
+sourceEl?.closest?.("[s-transition]")?.getAttribute("s-transition") || null;
+let scroll = sourceEl?.closest?.("[s-scroll]")?.getAttribute("s-scroll") || null;
+const fullUrl = new URL(url, location.origin).href;
+let targetEl = target || document.body;
+const targetSelector = sourceEl?.getAttribute("s-target") || null;
+const shouldPushHistory = !skipHistory && !targetSelector && method === "GET";
+const event = new CustomEvent("silcrow:navigate", {
+bubbles: true,
+cancelable: true,
+detail: {url: fullUrl, method, trigger, target: targetEl},
+});
+if (!document.dispatchEvent(event)) return;
+const prevAbort = abortMap.get(targetEl);
+if (prevAbort && prevAbort.method === "GET") {
+prevAbort.controller.abort();
+}
+const controller = new AbortController();
+abortMap.set(targetEl, {controller, method});
+const timeout = getTimeout(sourceEl);
+let timedOut = false;
+const timeoutId = setTimeout(() => {timedOut = true; controller.abort();}, timeout);
+showLoading(targetEl);
+try {
+const wantsHTML = wantsHTMLOption ?? !!sourceEl?.hasAttribute("s-html");
+const cached = method === "GET" ? cacheGet(cacheKey(fullUrl, wantsHTML)) : null;
+let text, contentType, redirected = false, finalUrl = fullUrl, pushUrl = null;
+let sideEffects = null, responseNonce = null;
+if (cached) {
+text = cached.text;
+contentType = cached.contentType;
+if (trigger !== "popstate") transition = cached.transition || transition;
+scroll = cached.scroll || scroll;
+responseNonce = cached.nonce || null;
+} else {
+const fetchOpts = buildFetchOptions(method, body, wantsHTML, controller.signal);
+const response = await fetch(fullUrl, fetchOpts);
+if (response.url && new URL(response.url).origin !== new URL(fullUrl).origin) {
+throw new Error("[silcrow] Refused cross-origin redirect: " + response.url);
+}
+if (!response.ok) {
+throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+}
+if (
+method === "GET" &&
+!targetSelector &&
+response.headers.get("silcrow-full-reload") === "true"
+) {
+window.location.assign(response.url || fullUrl);
+return;
+}
+const headerResult = processResponseHeaders(response, fullUrl);
+redirected = headerResult.redirected;
+finalUrl = headerResult.finalUrl;
+pushUrl = headerResult.pushUrl;
+sideEffects = headerResult.sideEffects;
+if (trigger !== "popstate") transition = headerResult.transition || transition;
+scroll = headerResult.scroll || scroll;
+if (headerResult.retargetSelector) {
+const newTarget = document.querySelector(headerResult.retargetSelector);
+if (newTarget) targetEl = newTarget;
+}
+text = await response.text();
+contentType = response.headers.get("Content-Type") || "";
+responseNonce = response.headers.get("silcrow-nonce");
+const cacheControl = response.headers.get("silcrow-cache");
+if (method === "GET" && !redirected && cacheControl !== "no-cache") {
+cacheSet(cacheKey(fullUrl, wantsHTML), {
+text, contentType, ts: Date.now(),
+transition: headerResult.transition, scroll: headerResult.scroll,
+nonce: responseNonce,
+});
+}
+if (method !== "GET") {
+bustCacheOnMutation();
+}
+}
+if (sourceEl && sourceEl.tagName === "FORM" && sourceEl.hasAttribute("s-autosave")) {
+clearDraft(sourceEl);
+}
+if (routeHandler) {
+const handled = await routeHandler({
+url: fullUrl, finalUrl, redirected, method,
+trigger, response: text, contentType, target: targetEl,
+});
+if (handled === false) {
+hideLoading(targetEl);
+return;
+}
+}
+if (shouldPushHistory && trigger !== "popstate") {
+const current = history.state || {};
+history.replaceState(
+{...current, scrollY: window.scrollY},
+"",
+location.href
+);
+}
+const {swapContent, isJSON} = prepareSwapContent(text, contentType, targetSelector);
+let swapExecuted = false;
+const proceed = () => {
+if (swapExecuted) return;
+swapExecuted = true;
+if (isJSON) {
+patch(swapContent, targetEl);
+} else {
+safeSetHTML(targetEl, swapContent, {
+allowStyleTags: method === "GET" && !targetSelector && targetEl === document.body,
+responseNonce,
+});
+}
+};
+const beforeSwap = new CustomEvent("silcrow:before-swap", {
+bubbles: true,
+cancelable: true,
+detail: {url: finalUrl, target: targetEl, content: swapContent, isJSON, proceed},
+});
+if (!document.dispatchEvent(beforeSwap)) return;
+if (!swapExecuted) await swapWithTransition(proceed, transition, direction);
+if (isJSON && method === "GET" && !targetSelector) {
+try {
+const pathKey = new URL(finalUrl).pathname;
+getOrCreateAtom(routeAtoms, pathKey, undefined).set(swapContent);
+prefetchPromises.set(pathKey, Promise.resolve(swapContent));
+} catch (e) {}
+}
+finalizeNavigation({
+pushUrl, redirected, finalUrl, fullUrl,
+shouldPushHistory, trigger, targetSelector, targetEl,
+sideEffects, transition, scroll, isJSON, wantsHTML,
+});
+} catch (err) {
+if (err.name === "AbortError") {
+if (timedOut) {
+const timeoutErr = new Error(
+`[silcrow] Request timed out after ${timeout}ms`
+);
+timeoutErr.name = "TimeoutError";
+document.dispatchEvent(
+new CustomEvent("silcrow:error", {
+bubbles: true,
+detail: {error: timeoutErr, url: fullUrl},
+})
+);
+if (errorHandler) {
+errorHandler(timeoutErr, {url: fullUrl, method, trigger, target: targetEl});
+}
+}
+return;
+}
+if (errorHandler) {
+errorHandler(err, {url: fullUrl, method, trigger, target: targetEl});
+} else {
+console.error("[silcrow]", err);
+}
+document.dispatchEvent(
+new CustomEvent("silcrow:error", {
+bubbles: true,
+detail: {error: err, url: fullUrl},
+})
+);
+} finally {
+clearTimeout(timeoutId);
+hideLoading(targetEl);
+abortMap.delete(targetEl);
+}
+}
+function navigateSameOrigin(url, options) {
+if (!isSameOriginUrl(url)) {
+const err = new Error("[silcrow] Refused cross-origin navigation: " + url);
+if (errorHandler) {
+errorHandler(err, {url, method: options.method || "GET", trigger: options.trigger, target: null});
+} else {
+console.error(err);
+}
+document.dispatchEvent(
+new CustomEvent("silcrow:error", {
+bubbles: true,
+detail: {error: err, url},
+})
+);
+return Promise.resolve();
+}
+return navigate(url, options);
+}
+async function onClick(e) {
+if (e.metaKey || e.ctrlKey || e.shiftKey || e.altKey) return;
+if (e.button !== 0) return;
+if (!e.target || typeof e.target.closest !== "function") return;
+const el = e.target.closest(VERB_SELECTOR);
+if (el && el.tagName !== "FORM") {
+e.preventDefault();
+const verb = resolveVerb(el);
+if (!verb) return;
+const inflight = preloadInflight.get(cacheKey(verb.url, el.hasAttribute("s-html")));
+if (inflight) await inflight;
+navigate(verb.url, {
+method: verb.method,
+target: getTarget(el),
+skipHistory: el.hasAttribute("s-skip-history"),
+sourceEl: el,
+trigger: "click",
+});
+return;
+}
+const anchor = e.target.closest("a[href]");
+if (!anchor || !isSafeBoostHref(anchor)) return;
+const boostEl = anchor.closest("[s-boost]");
+if (!boostEl) return;
+e.preventDefault();
+const boostedUrl = new URL(anchor.getAttribute("href"), location.origin).href;
+const inflight = preloadInflight.get(cacheKey(boostedUrl, anchor.hasAttribute("s-html")));
+if (inflight) await inflight;
+navigate(boostedUrl, {
+method: "GET",
+target: getBoostTarget(boostEl),
+skipHistory: anchor.hasAttribute("s-skip-history"),
+sourceEl: anchor,
+trigger: "click",
+});
+}
+function onSubmit(e) {
+if (!e.target || typeof e.target.closest !== "function") return;
+const form = e.target.closest(FORM_VERB_SELECTOR);
+if (!form) return;
+e.preventDefault();
+const verb = resolveVerb(form);
+if (!verb) return;
+const formData = new FormData(form);
+if (verb.method === "GET") {
+const actionUrl = new URL(verb.url, location.origin);
+for (const [k, v] of formData) {
+actionUrl.searchParams.append(k, v);
+}
+navigate(actionUrl.href, {
+method: verb.method,
+target: getTarget(form),
+sourceEl: form,
+trigger: "submit",
+});
+} else {
+const hasFiles = [...formData.values()].some(v => v instanceof File);
+navigate(verb.url, {
+method: verb.method,
+body: hasFiles ? formData : new URLSearchParams(formData),
+target: getTarget(form),
+sourceEl: form,
+trigger: "submit",
+});
+}
+}
+function onPopState(e) {
+if (!e.state) return;
+const url = location.href;
+const state = e.state;
+const targetSelector = state.targetSelector;
+const target = targetSelector
+? document.querySelector(targetSelector)
+: document.body;
+const idx = typeof state.idx === "number" ? state.idx : 0;
+const direction = idx < historyIdx ? "back" : "forward";
+const transition = direction === "back" ? historyTransition : (state.transition || null);
+historyIdx = idx;
+historyTransition = state.transition || null;
+navigate(url, {
+method: "GET",
+target: target || document.body,
+trigger: "popstate",
+skipHistory: true,
+transition,
+direction,
+wantsHTML: !!state.wantsHTML,
+});
+}
+function startPreload(url, wantsHTML) {
+const key = cacheKey(url, wantsHTML);
+if (responseCache.has(key) || preloadInflight.has(key)) return;
+const controller = new AbortController();
+const promise = fetch(url, {
+headers: {"silcrow-target": "true", "Accept": wantsHTML ? "text/html" : "application/json"},
+signal: controller.signal,
+})
+.then((r) => {
+if (!r.ok) throw new Error(`HTTP ${r.status}`);
+if (r.url && new URL(r.url).origin !== new URL(url).origin) return null;
+if (r.headers.get("silcrow-full-reload") === "true") return null;
+const contentType = r.headers.get("Content-Type") || "";
+const cacheControl = r.headers.get("silcrow-cache");
+const transition = r.headers.get("silcrow-transition");
+const scroll = r.headers.get("silcrow-scroll");
+const nonce = r.headers.get("silcrow-nonce");
+return r.text().then((text) => ({text, contentType, cacheControl, transition, scroll, nonce}));
+})
+.then((entry) => {
+if (!entry) return;
+const {text, contentType, cacheControl, transition, scroll, nonce} = entry;
+if (cacheControl !== "no-cache") {
+cacheSet(key, {text, contentType, ts: Date.now(), transition, scroll, nonce});
+}
+})
+.catch(() => {})
+.finally(() => preloadInflight.delete(key));
+preloadInflight.set(key, promise);
+}
+function onMouseEnter(e) {
+if (!e.target || typeof e.target.closest !== "function") return;
+const el = e.target.closest("[s-preload]");
+if (!el) return;
+const verb = resolveVerb(el);
+if (verb) {
+startPreload(verb.url, el.hasAttribute("s-html"));
+return;
+}
+if (el.tagName === "A" && isSafeBoostHref(el) && el.closest("[s-boost]")) {
+const url = new URL(el.getAttribute("href"), location.origin).href;
+startPreload(url, el.hasAttribute("s-html"));
+}
+}
+const DRAFT_PREFIX = "silcrow:draft:";
+const DRAFT_DEBOUNCE = 400;
+const DRAFT_TTL = 24 * 60 * 60 * 1000;
+const DRAFT_SKIP_TYPES = new Set([
+"button", "file", "hidden", "image", "password", "reset", "submit",
+]);
+const draftTimers = new WeakMap();
+const restoredDrafts = new WeakSet();
+let restoringDraft = false;
+function draftKey(form) {
+if (!form.id) {
+warn("s-autosave requires an id on the form");
+return null;
+}
+return DRAFT_PREFIX + location.pathname + "#" + form.id;
+}
+function isSensitiveField(el) {
+const tokens = String(el.getAttribute("autocomplete") || "").toLowerCase().split(/\s+/);
+return tokens.some(t => t === "off" || t === "one-time-code" || t.startsWith("cc-"));
+}
+function isDraftField(el) {
+if (!el.name || el.disabled) return false;
+if (!("value" in el) || el.tagName === "BUTTON") return false;
+if (DRAFT_SKIP_TYPES.has(String(el.type || "").toLowerCase())) return false;
+return !isSensitiveField(el);
+}
+function serializeDraft(form) {
+const values = {};
+for (const el of form.elements) {
+if (!isDraftField(el)) continue;
+if (!Object.prototype.hasOwnProperty.call(values, el.name)) values[el.name] = [];
+if (el.type === "checkbox" || el.type === "radio") {
+if (el.checked) values[el.name].push(el.value);
+} else if (el.tagName === "SELECT" && el.multiple) {
+for (const opt of el.selectedOptions) values[el.name].push(opt.value);
+} else {
+values[el.name].push(el.value);
+}
+}
+return values;
+}
+function saveDraft(form, key) {
+if (!key || !form.isConnected) return;
+const entry = {
+v: form.getAttribute("s-autosave") || "",
+ts: Date.now(),
+values: serializeDraft(form),
+};
+try {
+localStorage.setItem(key, JSON.stringify(entry));
+} catch (e) {
+warn("Failed to save draft: " + e.message);
+}
+}
+function restoreDraft(form) {
+if (restoredDrafts.has(form)) return;
+restoredDrafts.add(form);
+const key = draftKey(form);
+if (!key) return;
+let entry = null;
+try {
+const raw = localStorage.getItem(key);
+if (raw) entry = JSON.parse(raw);
+} catch (e) {
+warn("Failed to read draft: " + e.message);
+}
+if (!entry || typeof entry !== "object" || !entry.values) return;
+if (
+entry.v !== (form.getAttribute("s-autosave") || "") ||
+!(Date.now() - entry.ts < DRAFT_TTL)
+) {
+clearDraft(form);
+return;
+}
+const values = entry.values;
+const consumed = {};
+const changed = [];
+for (const el of form.elements) {
+if (!isDraftField(el)) continue;
+if (!Object.prototype.hasOwnProperty.call(values, el.name)) continue;
+const saved = Array.isArray(values[el.name]) ? values[el.name].map(String) : [];
+if (el.type === "checkbox" || el.type === "radio") {
+const next = saved.includes(el.value);
+if (el.checked !== next) { el.checked = next; changed.push(el); }
+} else if (el.tagName === "SELECT" && el.multiple) {
+let dirty = false;
+for (const opt of el.options) {
+const next = saved.includes(opt.value);
+if (opt.selected !== next) { opt.selected = next; dirty = true; }
+}
+if (dirty) changed.push(el);
+} else {
+const idx = consumed[el.name] || 0;
+if (idx < saved.length && el.value !== saved[idx]) {
+el.value = saved[idx];
+changed.push(el);
+}
+consumed[el.name] = idx + 1;
+}
+}
+restoringDraft = true;
+try {
+for (const el of changed) {
+el.dispatchEvent(new Event("input", {bubbles: true}));
+el.dispatchEvent(new Event("change", {bubbles: true}));
+}
+} finally {
+restoringDraft = false;
+}
+form.dispatchEvent(new CustomEvent("silcrow:draft:restore", {
+bubbles: true,
+detail: {form, savedAt: entry.ts},
+}));
+}
+function clearDraft(form) {
+const timer = draftTimers.get(form);
+if (timer) {
+clearTimeout(timer);
+draftTimers.delete(form);
+}
+const key = draftKey(form);
+if (!key) return;
+try {
+localStorage.removeItem(key);
+} catch (e) {}
+}
+function initDrafts(root) {
+root.querySelectorAll("form[s-autosave]").forEach(restoreDraft);
+}
+function onDraftInput(e) {
+if (restoringDraft) return;
+if (!e.target || typeof e.target.closest !== "function") return;
+const form = e.target.form || e.target.closest("form");
+if (!form || !form.hasAttribute("s-autosave")) return;
+if (!isDraftField(e.target)) return;
+const key = draftKey(form);
+if (!key) return;
+const prev = draftTimers.get(form);
+if (prev) clearTimeout(prev);
+draftTimers.set(form, setTimeout(function () {
+draftTimers.delete(form);
+saveDraft(form, key);
+}, DRAFT_DEBOUNCE));
+}
+const snapshots = new WeakMap();
+function optimisticPatch(data, root) {
+const element = typeof root === "string" ? document.querySelector(root) : root;
+if (!element) {
+warn("Optimistic root not found: " + root);
+return;
+}
+snapshots.set(element, element.innerHTML);
+patch(data, element);
+document.dispatchEvent(
+new CustomEvent("silcrow:optimistic", {
+bubbles: true,
+detail: {root: element, data},
+})
+);
+}
+function revertOptimistic(root) {
+const element = typeof root === "string" ? document.querySelector(root) : root;
+if (!element) {
+warn("Revert root not found: " + root);
+return;
+}
+const saved = snapshots.get(element);
+if (saved === undefined) {
+warn("No snapshot to revert for element");
+return;
+}
+element.innerHTML = saved;
+snapshots.delete(element);
+invalidate(element);
+document.dispatchEvent(
+new CustomEvent("silcrow:revert", {
+bubbles: true,
+detail: {root: element},
+})
+);
+}
+let liveObserver = null;
+let middlewareLocked = false;
+function init() {
+document.addEventListener("click", onClick);
+document.addEventListener("submit", onSubmit);
+window.addEventListener("popstate", onPopState);
+document.addEventListener("mouseenter", onMouseEnter, true);
+document.addEventListener("silcrow:sse", onSSEEvent);
+document.addEventListener("input", onDraftInput);
+document.addEventListener("change", onDraftInput);
+if (!history.state?.silcrow) {
+history.replaceState({silcrow: true, url: location.href, idx: 0}, "", location.href);
+}
+historyIdx = typeof history.state.idx === "number" ? history.state.idx : 0;
+historyTransition = history.state.transition || null;
+seedAtomsFromSSR();
+initLiveElements();
+initScopeBindings();
+initDrafts(document);
+document.addEventListener("silcrow:sse:live", function (e) {
+const data = e.detail && e.detail.data;
+if (!data || typeof data !== "object" || Array.isArray(data)) return;
+if (typeof window.__pilcrow_live_patch === "function") {
+window.__pilcrow_live_patch(data);
+} else {
+Object.keys(data).forEach(function (k) {
+const v = data[k];
+document.querySelectorAll('[data-pilcrow-live-field="' + k + '"]').forEach(function (n) {
+n.textContent = v == null ? "" : String(v);
+});
+});
+}
+});
+liveObserver = new MutationObserver(function (mutations) {
+function cleanupLiveNode(node) {
+const state = liveConnections.get(node);
+if (!state) return;
+if (state.protocol === "ws") {
+unsubscribeWs(node);
+} else {
+pauseLiveState(state);
+unregisterLiveState(state);
+}
+}
+for (const mutation of mutations) {
+for (const removed of mutation.removedNodes) {
+if (removed.nodeType !== 1) continue;
+cleanupLiveNode(removed);
+unbindElementAtoms(removed);
+if (removed.querySelectorAll) {
+for (const child of removed.querySelectorAll("[s-sse], [s-ws], [s-wss]")) {
+cleanupLiveNode(child);
+}
+for (const child of removed.querySelectorAll("[s-bind]")) {
+unbindElementAtoms(child);
+}
+}
+}
+}
+});
+liveObserver.observe(document.body, {childList: true, subtree: true});
+if (window.__psData) {
+patch(window.__psData, document.body);
+delete window.__psData;
+}
+middlewareLocked = true;
+}
+function destroy() {
+document.removeEventListener("click", onClick);
+document.removeEventListener("submit", onSubmit);
+window.removeEventListener("popstate", onPopState);
+document.removeEventListener("mouseenter", onMouseEnter, true);
+document.removeEventListener("silcrow:sse", onSSEEvent);
+document.removeEventListener("input", onDraftInput);
+document.removeEventListener("change", onDraftInput);
+if (liveObserver) {
+liveObserver.disconnect();
+liveObserver = null;
+}
+responseCache.clear();
+preloadInflight.clear();
+destroyAllLive();
+routeAtoms.clear();
+streamAtoms.clear();
+scopeAtoms.clear();
+prefetchPromises.clear();
+}
+window.Silcrow = {
+patch,
+invalidate,
+stream,
+go(path, options = {}) {
+return navigateSameOrigin(path, {
+method: options.method || (options.body ? "POST" : "GET"),
+body: options.body || null,
+target: options.target ? document.querySelector(options.target) : null,
+skipHistory: options.skipHistory || false,
+transition: options.transition || null,
+trigger: "api",
+});
+},
+live: openLive,
+send: sendWs,
+disconnect: disconnectLive,
+reconnect: reconnectLive,
+prefetch: prefetchRoute,
+submit: submitAction,
+subscribe(scope, fn) {
+const atom = resolveAtomByScope(scope, true);
+return atom ? atom.subscribe(fn) : function () {};
+},
+snapshot(scope) {
+const atom = resolveAtomByScope(scope, false);
+return atom ? atom.get() : undefined;
+},
+publish(scope, data) {
+const atom = resolveAtomByScope(scope, true);
+if (atom) atom.patch(data);
+},
+optimistic: optimisticPatch,
+revert: revertOptimistic,
+onToast: (handler) => {setToastHandler(handler); return window.Silcrow;},
+use(fn) {
+if (middlewareLocked) {
+warn("Silcrow.use() called after init — middleware registration is closed.");
+return this;
+}
+if (typeof fn === 'function') patchMiddleware.push(fn);
+return this;
+},
+onRoute: (h) => {routeHandler = h; return window.Silcrow;},
+onError: (h) => {errorHandler = h; return window.Silcrow;},
+destroy,
+};
+if (document.readyState === "loading") {
+document.addEventListener("DOMContentLoaded", init);
+} else {
+init();
+}
+})();